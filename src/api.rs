@@ -0,0 +1,119 @@
+use colored::*;
+use regex::Regex;
+
+use crate::urls::{STATIC_EXTENSIONS, normalize_url_for_api};
+
+// API过滤
+pub fn filter_api_path(path: &str, base_urls: &[String], api_core: &str, noise_strings: &[String]) -> Vec<String> {
+    let trimmed = path.trim();
+    println!(
+        "{} {}",
+        "[*]检查路径:".truecolor(128, 128, 128),
+        trimmed.truecolor(128, 128, 128)
+    );
+
+    // 排除 Base64 编码字符串
+    if trimmed.contains("data:")
+        || trimmed.contains("base64")
+        || trimmed
+            .chars()
+            .filter(|&c| c == '+' || c == '/' || c == '=')
+            .count()
+            > 5
+        || trimmed.len() > 100
+    {
+        println!("{} {}", "[*]Base64字符串过滤:".red(), trimmed.red());
+        return vec![];
+    }
+
+    // 排除长度不符合的路径
+    if trimmed.len() < 2 || trimmed.len() > 500 {
+        println!("{} {}", "[*]路径长度不符合:".red(), trimmed.red());
+        return vec![];
+    }
+
+    // 排除包含中文的路径
+    let chinese_re = Regex::new(r"\p{Han}").unwrap();
+    if chinese_re.is_match(trimmed) {
+        println!("{} {}", "[*]包含中文过滤:".red(), trimmed.red());
+        return vec![];
+    }
+
+    // 排除噪音字符串
+    if noise_strings.iter().any(|noise| trimmed.contains(noise))
+        || trimmed.contains("<?")
+        || trimmed.contains("?>")
+        || trimmed.contains("</")
+        || trimmed.starts_with("/#")
+        || trimmed.contains("/g,c=r(")
+        || trimmed.contains("schemeClr")
+        || trimmed.contains("'>")
+        || trimmed.contains("<a:")
+        || trimmed.contains("</a:")
+        || trimmed.contains("length")
+        || trimmed.contains("\\")
+    {
+        println!("{} {}", "[*]垃圾字符串过滤:".red(), trimmed.red());
+        return vec![];
+    }
+
+    let cleaned = urlencoding::decode(trimmed)
+        .unwrap_or(trimmed.to_string().into())
+        .into_owned();
+
+    let (path_part, query_part) = cleaned.split_once('?').unwrap_or((&cleaned, ""));
+    let has_query = !query_part.is_empty();
+
+    if path_part.contains("image")
+        || path_part.contains("img")
+        || path_part.contains("css")
+        || path_part.contains("font")
+        || path_part.contains("svg")
+        || path_part.contains("swf")
+        || path_part.contains("ttf")
+        || STATIC_EXTENSIONS.iter().any(|ext| path_part.ends_with(ext))
+    {
+        println!("{} {}", "[*]排除资源类路径:".red(), cleaned.red());
+        return vec![];
+    }
+
+    if has_query && STATIC_EXTENSIONS.iter().any(|ext| path_part.contains(ext)) {
+        println!("{} {}", "[*]排除带查询参数的静态资源:".red(), cleaned.red());
+        return vec![];
+    }
+
+    let is_explicit_api = path_part == "api"
+        || path_part == "api/"
+        || path_part == "/api"
+        || path_part == "/api/"
+        || path_part.starts_with("api/")
+        || path_part.contains("/api/")
+        || (path_part.contains("/api/") && path_part.split("/").count() > 2);
+
+    let is_restful_path = path_part.starts_with("/")
+        && path_part.split("/").count() >= 2
+        && !path_part.contains(".")
+        && path_part
+            .chars()
+            .all(|c| c.is_alphanumeric() || c == '/' || c == '-' || c == '_');
+
+    if !is_explicit_api && !is_restful_path {
+        println!("{} {}", "[*]非API路径:".red(), cleaned.red());
+        return vec![];
+    }
+
+    println!("{} {:?}", "[*]使用base_urls:".cyan(), base_urls);
+    let mut results = Vec::new();
+    for base in base_urls {
+        let full_url = normalize_url_for_api(&cleaned, base, api_core);
+        if !full_url.is_empty() {
+            if is_explicit_api {
+                println!("{} {}", "[*]API:".green(), full_url.green());
+            } else if is_restful_path {
+                println!("{} {}", "[!]疑似RESTful接口:".purple(), full_url.purple());
+            }
+            results.push(full_url);
+        }
+    }
+    results
+}
@@ -0,0 +1,89 @@
+use std::io::BufRead;
+use std::panic::{self, AssertUnwindSafe};
+use std::sync::Mutex;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::thread;
+
+use colored::*;
+
+use crate::Result;
+use crate::model::ScanReport;
+use crate::scanner::Scanner;
+
+// 单个目标的扫描结果，失败时保存错误信息
+#[derive(Debug)]
+pub struct TargetOutcome {
+    pub target: String,
+    pub result: std::result::Result<ScanReport, String>,
+}
+
+// 读取目标列表：每行一个URL，忽略空行与 # 开头的注释
+pub fn read_targets(reader: impl BufRead) -> Result<Vec<String>> {
+    let mut targets = Vec::new();
+    for line in reader.lines() {
+        let line = line?;
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+        if !targets.iter().any(|t| t == line) {
+            targets.push(line.to_string());
+        }
+    }
+    Ok(targets)
+}
+
+// 批量扫描，jobs 为并行扫描的目标数；单个目标失败不影响其他目标
+pub fn scan_targets(scanner: &Scanner, targets: &[String], jobs: usize) -> Vec<TargetOutcome> {
+    let jobs = jobs.clamp(1, targets.len().max(1));
+    let next = AtomicUsize::new(0);
+    let outcomes: Mutex<Vec<Option<TargetOutcome>>> =
+        Mutex::new((0..targets.len()).map(|_| None).collect());
+
+    thread::scope(|scope| {
+        for _ in 0..jobs {
+            scope.spawn(|| {
+                loop {
+                    let index = next.fetch_add(1, Ordering::SeqCst);
+                    let Some(target) = targets.get(index) else {
+                        break;
+                    };
+                    println!(
+                        "{} [{}/{}] {}",
+                        "[*]开始扫描目标:".bright_blue(),
+                        index + 1,
+                        targets.len(),
+                        target.green()
+                    );
+                    let outcome = TargetOutcome {
+                        target: target.clone(),
+                        result: scan_one(scanner, target),
+                    };
+                    if let Err(e) = &outcome.result {
+                        println!("{} {} - {}", "[*]目标扫描失败:".red(), target.red(), e.red());
+                    }
+                    outcomes.lock().unwrap()[index] = Some(outcome);
+                }
+            });
+        }
+    });
+
+    outcomes
+        .into_inner()
+        .unwrap()
+        .into_iter()
+        .flatten()
+        .collect()
+}
+
+fn scan_one(scanner: &Scanner, target: &str) -> std::result::Result<ScanReport, String> {
+    match panic::catch_unwind(AssertUnwindSafe(|| scanner.scan_url(target))) {
+        Ok(Ok(result)) => Ok(result),
+        Ok(Err(e)) => Err(e.to_string()),
+        Err(payload) => Err(payload
+            .downcast_ref::<&str>()
+            .map(|s| s.to_string())
+            .or_else(|| payload.downcast_ref::<String>().cloned())
+            .unwrap_or_else(|| "扫描过程中发生panic".to_string())),
+    }
+}
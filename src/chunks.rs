@@ -0,0 +1,124 @@
+// 懒加载 chunk：从 webpack 运行时的 chunkId -> hash 映射与 Vite 的预加载依赖列表还原 chunk 地址
+
+use std::collections::HashMap;
+
+use colored::*;
+use regex::Regex;
+use url::Url;
+
+// 脚本中引用的全部 chunk 地址，script_url 为脚本所在地址（内联脚本为页面地址）
+pub fn discover_chunks(content: &str, script_url: &str) -> Vec<String> {
+    let Ok(script_url) = Url::parse(script_url) else {
+        return Vec::new();
+    };
+    let mut chunks = webpack_chunks(content, &script_url);
+    chunks.extend(vite_chunks(content, &script_url));
+    chunks.sort();
+    chunks.dedup();
+    for chunk in &chunks {
+        println!("{} {}", "[*]发现chunk:".cyan(), chunk.cyan());
+    }
+    chunks
+}
+
+// webpack 的 chunk 文件名函数，例如
+// __webpack_require__.u = e => "static/js/" + e + "." + {12:"a1b2"}[e] + ".chunk.js"
+// webpack 4: "js/" + ({about:"about"}[e] || e) + "." + {about:"c3d4"}[e] + ".js"
+fn webpack_chunks(content: &str, script_url: &Url) -> Vec<String> {
+    let filename_re = Regex::new(
+        r#"["']([^"'\s]*)["']\s*\+\s*(?:\(\s*(\{[^{}]*\})\s*\[\s*\w+\s*\]\s*\|\|\s*\w+\s*\)|\w+)\s*\+\s*["']([^"'\s]*)["']\s*\+\s*(\{[^{}]*\})\s*\[\s*\w+\s*\]\s*\+\s*["']([^"'\s]*\.js)["']"#,
+    )
+    .unwrap();
+    let public_path = public_path(content);
+
+    let mut chunks = Vec::new();
+    for cap in filename_re.captures_iter(content) {
+        let prefix = &cap[1];
+        let names = cap.get(2).map(|m| parse_chunk_map(m.as_str())).unwrap_or_default();
+        let separator = &cap[3];
+        let hashes = parse_chunk_map(&cap[4]);
+        let suffix = &cap[5];
+        let Some(base) = chunk_base(script_url, public_path.as_deref(), prefix) else {
+            continue;
+        };
+        for (id, hash) in &hashes {
+            let name = names.get(id).unwrap_or(id);
+            let file = format!("{}{}{}{}{}", prefix, name, separator, hash, suffix);
+            if let Ok(chunk_url) = base.join(&file) {
+                chunks.push(chunk_url.to_string());
+            }
+        }
+    }
+    chunks
+}
+
+// webpack 运行时的 publicPath：__webpack_require__.p = "..."；
+// 压缩后的 n.p = "..." 只有同一对象还定义了 n.u 或 n.e（chunk 文件名与加载函数）时才认为是运行时
+fn public_path(content: &str) -> Option<String> {
+    let assign_re = Regex::new(r#"(?:^|[^\w$.])([\w$]+)\.p\s*=\s*["']([^"']*)["']"#).unwrap();
+    assign_re.captures_iter(content).find_map(|cap| {
+        let object = &cap[1];
+        let runtime = object == "__webpack_require__"
+            || Regex::new(&format!(r"(?:^|[^\w$.]){}\.[ue]\s*=", regex::escape(object)))
+                .is_ok_and(|re| re.is_match(content));
+        runtime.then(|| cap[2].to_string())
+    })
+}
+
+// 对象字面量 {12:"a1b2","about":"c3d4"} 中的键值对
+fn parse_chunk_map(map: &str) -> HashMap<String, String> {
+    let entry_re = Regex::new(r#"["']?([\w.\-/]+)["']?\s*:\s*["']([^"']+)["']"#).unwrap();
+    entry_re
+        .captures_iter(map)
+        .map(|cap| (cap[1].to_string(), cap[2].to_string()))
+        .collect()
+}
+
+// chunk 文件名的拼接基准：优先使用 publicPath；没有时取脚本路径中文件名前缀之前的部分，找不到前缀则为脚本所在目录
+fn chunk_base(script_url: &Url, public_path: Option<&str>, prefix: &str) -> Option<Url> {
+    match public_path {
+        Some(public_path) if !public_path.is_empty() => {
+            let public_path = if public_path.ends_with('/') {
+                public_path.to_string()
+            } else {
+                format!("{}/", public_path)
+            };
+            script_url.join(&public_path).ok()
+        }
+        _ => {
+            let path = script_url.path();
+            let dir = match path.rfind('/') {
+                Some(index) => &path[..=index],
+                None => "/",
+            };
+            let root = match dir.find(&format!("/{}", prefix.trim_start_matches('/'))) {
+                Some(index) if !prefix.is_empty() => &dir[..=index],
+                _ => dir,
+            };
+            script_url.join(root).ok()
+        }
+    }
+}
+
+// Vite 的预加载依赖列表，例如
+// const __vite__mapDeps = (i, m = __vite__mapDeps, d = (m.f || (m.f = ["assets/About-a1b2.js", ...])))
+// __vitePreload(() => import("./About-a1b2.js"), ["assets/About-a1b2.js"])
+fn vite_chunks(content: &str, script_url: &Url) -> Vec<String> {
+    if !content.contains("__vite__mapDeps") && !content.contains("__vitePreload") {
+        return Vec::new();
+    }
+    // 列表中的路径相对站点根目录（Vite 默认 base 为 /），./ 开头的相对脚本所在目录
+    let asset_re = Regex::new(r#"["']((?:\./|/)?assets/[^"'\s]+\.js)["']"#).unwrap();
+    asset_re
+        .captures_iter(content)
+        .filter_map(|cap| {
+            let path = &cap[1];
+            let resolved = if path.starts_with("./") {
+                script_url.join(path)
+            } else {
+                script_url.join(&format!("/{}", path.trim_start_matches('/')))
+            };
+            resolved.ok().map(String::from)
+        })
+        .collect()
+}
@@ -0,0 +1,351 @@
+use std::path::PathBuf;
+use std::time::Duration;
+
+use clap::{Args, Parser, Subcommand, ValueEnum};
+use web_scraper::ScanOptions;
+use web_scraper::crawler::{DEFAULT_MAX_VARIANTS, Scope};
+use web_scraper::http::MIN_RATE_LIMIT;
+use web_scraper::output::OutputFormat;
+
+// 命令行参数
+#[derive(Debug, Parser)]
+#[command(name = "web_scraper", version, about = "爬取站点、提取JS接口并探测存活")]
+#[command(args_conflicts_with_subcommands = true)]
+// 不带任何参数时显示完整帮助（含子命令），而不是兼容用法缺少 --url 的报错
+#[command(arg_required_else_help = true)]
+// 使用子命令时不再要求兼容用法的 --url / --file
+#[command(subcommand_negates_reqs = true)]
+pub struct Cli {
+    #[command(subcommand)]
+    pub command: Option<Command>,
+
+    // 兼容旧用法：web_scraper -u <URL> [-c ...] [-a ...] 等同于 scan，仅在未给出子命令时生效
+    #[command(flatten)]
+    pub scan: ScanArgs,
+}
+
+#[derive(Debug, Subcommand)]
+pub enum Command {
+    /// 完整扫描：爬取 -> JS提取 -> 存活探测 -> 输出结果文件
+    Scan(ScanArgs),
+    /// 仅爬取页面，列出 HTML 页面与静态资源
+    Crawl(CrawlArgs),
+    /// 爬取并从JS中提取接口地址
+    Extract(CrawlArgs),
+    /// 探测给定URL的存活状态
+    Probe(ProbeArgs),
+    /// 重新展示已输出的结果，或转换 JSON 报告的格式
+    Report(ReportArgs),
+}
+
+#[derive(Debug, Args)]
+pub struct TargetArgs {
+    /// 目标URL，例如 https://example.com
+    #[arg(short = 'u', long = "url", value_name = "URL", value_parser = parse_url)]
+    pub url: String,
+}
+
+#[derive(Debug, Args)]
+pub struct RequestArgs {
+    /// Cookie 请求头
+    #[arg(short = 'c', long, value_name = "COOKIE")]
+    pub cookie: Option<String>,
+
+    /// Authorization 请求头
+    #[arg(short = 'a', long = "auth", value_name = "AUTHORIZATION")]
+    pub authorization: Option<String>,
+
+    /// User-Agent 请求头
+    #[arg(long, value_name = "UA")]
+    pub user_agent: Option<String>,
+
+    /// 自定义请求头，格式 "Name: Value"，可重复
+    #[arg(short = 'H', long = "header", value_name = "HEADER", value_parser = parse_header)]
+    pub headers: Vec<(String, String)>,
+
+    /// 每个主机每秒最多请求数
+    #[arg(long, value_name = "RPS", value_parser = parse_rate)]
+    pub rate_limit: Option<f64>,
+
+    /// 每次请求前随机等待的最长毫秒数
+    #[arg(long, value_name = "MS", default_value_t = 0)]
+    pub jitter: u64,
+
+    /// 每个主机同时进行中的最大请求数
+    #[arg(long, value_name = "N", value_parser = clap::value_parser!(u16).range(1..))]
+    pub max_per_host: Option<u16>,
+
+    /// 整个扫描的请求总数上限，批量扫描时所有目标共用；耗尽后的目标在汇总中标记为不完整
+    #[arg(long, value_name = "N")]
+    pub max_requests: Option<usize>,
+
+    /// 不自动跟随跳转：逐跳记录跳转链，探测结果显示 3xx 状态码并标记跳转到登录页的接口
+    #[arg(long)]
+    pub no_follow_redirects: bool,
+}
+
+#[derive(Debug, Args)]
+pub struct CrawlSettings {
+    /// 主域名页面最大爬取深度
+    #[arg(short = 'd', long, value_name = "N", default_value_t = 3)]
+    pub depth: u8,
+
+    /// 跟随第三方页面时的最大爬取深度
+    #[arg(long, value_name = "N", default_value_t = 1)]
+    pub third_party_depth: u8,
+
+    /// 爬取范围外的第三方页面
+    #[arg(long)]
+    pub follow_third_party: bool,
+
+    /// 属于目标的主机范围
+    #[arg(long, value_enum, default_value_t = ScopeArg::Host)]
+    pub scope: ScopeArg,
+
+    /// 范围内的主机，可多次指定或以逗号分隔，配合 --scope list 使用
+    #[arg(long = "scope-host", value_name = "HOST", value_delimiter = ',')]
+    #[arg(required_if_eq("scope", "list"))]
+    pub scope_hosts: Vec<String>,
+
+    /// 最多抓取的页面数
+    #[arg(long, value_name = "N")]
+    pub max_pages: Option<usize>,
+
+    /// 只有参数值不同或路径段重复的URL视为同一模式，每个模式最多抓取的页面数，0 表示不限制
+    #[arg(long, value_name = "N", default_value_t = DEFAULT_MAX_VARIANTS)]
+    pub max_variants: usize,
+
+    /// 跟随脚本中 import()、importScripts、Worker 等引用的最大深度，0 表示只处理页面直接引用的JS
+    #[arg(long, value_name = "N", default_value_t = 3)]
+    pub js_depth: u8,
+
+    /// 单个目标的最长扫描时间，例如 30s、10m、1h
+    #[arg(long, value_name = "DURATION", value_parser = humantime::parse_duration)]
+    pub max_duration: Option<Duration>,
+
+    /// 爬取并发线程数
+    #[arg(short = 't', long, value_name = "N", default_value_t = 8)]
+    #[arg(value_parser = clap::value_parser!(u16).range(1..))]
+    pub concurrency: u16,
+
+    /// 不从 robots.txt 与 sitemap.xml 获取种子URL
+    #[arg(long)]
+    pub no_seeds: bool,
+
+    /// 同时爬取 robots.txt 中 Disallow 的路径，默认只记录为敏感信息
+    #[arg(long)]
+    pub robots_seeds: bool,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum ScopeArg {
+    /// 仅目标主机
+    Host,
+    /// 目标可注册域名（按公共后缀列表计算）下的全部子域名
+    Domain,
+    /// 目标主机与 --scope-host 列出的主机
+    List,
+}
+
+#[derive(Debug, Args)]
+pub struct OutputArgs {
+    /// 结果输出目录
+    #[arg(short = 'o', long, value_name = "DIR", default_value = "output")]
+    pub output: PathBuf,
+
+    /// 结果文件格式
+    #[arg(short = 'F', long, value_name = "FORMAT", value_enum, default_value_t = FormatArg::Csv)]
+    pub format: FormatArg,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum FormatArg {
+    /// 探测结果表 + 敏感信息表
+    Csv,
+    /// 单个 JSON 报告文档
+    Json,
+    /// 每行一个 URL/探测/敏感信息/域名 事件
+    Jsonl,
+    /// 可排序、可筛选的单文件 HTML 报告
+    Html,
+    /// SARIF 2.1.0，仅包含敏感信息
+    Sarif,
+}
+
+impl From<FormatArg> for OutputFormat {
+    fn from(format: FormatArg) -> Self {
+        match format {
+            FormatArg::Csv => OutputFormat::Csv,
+            FormatArg::Json => OutputFormat::Json,
+            FormatArg::Jsonl => OutputFormat::Jsonl,
+            FormatArg::Html => OutputFormat::Html,
+            FormatArg::Sarif => OutputFormat::Sarif,
+        }
+    }
+}
+
+#[derive(Debug, Args)]
+pub struct ScanArgs {
+    /// 目标URL，例如 https://example.com
+    #[arg(short = 'u', long = "url", value_name = "URL", value_parser = parse_url)]
+    #[arg(required_unless_present = "file")]
+    pub url: Option<String>,
+
+    /// 目标列表文件，每行一个URL，"-" 表示从标准输入读取
+    #[arg(short = 'f', long = "file", value_name = "FILE", conflicts_with = "url")]
+    pub file: Option<PathBuf>,
+
+    /// 批量扫描时并行扫描的目标数
+    #[arg(short = 'j', long, value_name = "N", default_value_t = 1)]
+    #[arg(value_parser = clap::value_parser!(u16).range(1..))]
+    pub jobs: u16,
+
+    #[command(flatten)]
+    pub request: RequestArgs,
+    #[command(flatten)]
+    pub crawl: CrawlSettings,
+    #[command(flatten)]
+    pub output: OutputArgs,
+}
+
+#[derive(Debug, Args)]
+pub struct CrawlArgs {
+    #[command(flatten)]
+    pub target: TargetArgs,
+    #[command(flatten)]
+    pub request: RequestArgs,
+    #[command(flatten)]
+    pub crawl: CrawlSettings,
+
+    /// extract 时 source map 还原的源码保存目录，按目标主机分子目录
+    #[arg(long, value_name = "DIR", default_value = "output/sourcemaps")]
+    pub source_map_dir: PathBuf,
+}
+
+#[derive(Debug, Args)]
+pub struct ProbeArgs {
+    /// 待探测的URL
+    #[arg(value_name = "URL", value_parser = parse_url, required_unless_present = "input")]
+    pub urls: Vec<String>,
+
+    /// 从文件读取待探测的URL，每行一个
+    #[arg(short = 'i', long, value_name = "FILE")]
+    pub input: Option<PathBuf>,
+
+    #[command(flatten)]
+    pub request: RequestArgs,
+    #[command(flatten)]
+    pub output: OutputArgs,
+}
+
+#[derive(Debug, Args)]
+pub struct ReportArgs {
+    /// scan 或 probe 输出的 CSV 或 JSON 文件
+    #[arg(value_name = "FILE")]
+    pub input: PathBuf,
+
+    /// 将 JSON 报告转换为指定格式并写入输出目录
+    #[arg(short = 'F', long, value_name = "FORMAT", value_enum)]
+    pub format: Option<FormatArg>,
+
+    /// 转换结果的输出目录
+    #[arg(short = 'o', long, value_name = "DIR", default_value = "output")]
+    pub output: PathBuf,
+}
+
+impl RequestArgs {
+    pub fn apply(&self, mut options: ScanOptions) -> ScanOptions {
+        if let Some(cookie) = &self.cookie {
+            options = options.cookie(cookie.as_str());
+        }
+        if let Some(authorization) = &self.authorization {
+            options = options.authorization(authorization.as_str());
+        }
+        if let Some(user_agent) = &self.user_agent {
+            options = options.user_agent(user_agent.as_str());
+        }
+        for (name, value) in &self.headers {
+            options = options.header(name.as_str(), value.as_str());
+        }
+        if let Some(rate) = self.rate_limit {
+            options = options.rate_limit(rate);
+        }
+        if self.jitter > 0 {
+            options = options.jitter(Duration::from_millis(self.jitter));
+        }
+        if let Some(max) = self.max_per_host {
+            options = options.max_per_host(max as usize);
+        }
+        if let Some(max) = self.max_requests {
+            options = options.max_requests(max);
+        }
+        options.follow_redirects(!self.no_follow_redirects)
+    }
+}
+
+impl CrawlSettings {
+    pub fn apply(&self, options: ScanOptions) -> ScanOptions {
+        let mut options = options
+            .max_depth(self.depth)
+            .third_party_depth(self.third_party_depth)
+            .concurrency(self.concurrency as usize)
+            .seeds(!self.no_seeds)
+            .robots_seeds(self.robots_seeds)
+            .follow_third_party(self.follow_third_party)
+            .max_variants(self.max_variants)
+            .js_depth(self.js_depth)
+            .scope(match self.scope {
+                ScopeArg::Host => Scope::Host,
+                ScopeArg::Domain => Scope::Domain,
+                ScopeArg::List => Scope::Hosts(self.scope_hosts.clone()),
+            });
+        if let Some(max) = self.max_pages {
+            options = options.max_pages(max);
+        }
+        if let Some(duration) = self.max_duration {
+            options = options.max_duration(duration);
+        }
+        options
+    }
+}
+
+pub fn parse_url(value: &str) -> Result<String, String> {
+    let value = value.trim();
+    match url::Url::parse(value) {
+        Ok(url) if url.scheme() == "http" || url.scheme() == "https" => {
+            if url.host_str().is_none() {
+                Err(format!("URL 缺少主机名: {}", value))
+            } else {
+                Ok(value.to_string())
+            }
+        }
+        Ok(url) => Err(format!(
+            "不支持的协议 {}，请提供完整的URL，例如 https://example.com",
+            url.scheme()
+        )),
+        Err(e) => Err(format!(
+            "无效URL ({})，请提供完整的URL，例如 https://example.com",
+            e
+        )),
+    }
+}
+
+fn parse_header(value: &str) -> Result<(String, String), String> {
+    let (name, header_value) = value
+        .split_once(':')
+        .ok_or_else(|| format!("请求头格式应为 \"Name: Value\"，实际为: {}", value))?;
+    let name = name.trim();
+    if name.is_empty() {
+        return Err(format!("请求头名称为空: {}", value));
+    }
+    reqwest::header::HeaderName::from_bytes(name.as_bytes())
+        .map_err(|_| format!("无效的请求头名称: {}", name))?;
+    Ok((name.to_string(), header_value.trim().to_string()))
+}
+
+fn parse_rate(value: &str) -> Result<f64, String> {
+    match value.parse::<f64>() {
+        Ok(rate) if rate >= MIN_RATE_LIMIT && rate.is_finite() => Ok(rate),
+        _ => Err(format!("每秒请求数不能小于 {}，实际为: {}", MIN_RATE_LIMIT, value)),
+    }
+}
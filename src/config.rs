@@ -0,0 +1,118 @@
+use std::fs::File;
+use std::io::{Read, Write};
+
+use colored::Colorize;
+
+// 配置：黑名单、api_core 与噪音字符串
+#[derive(Debug, Clone)]
+pub struct Config {
+    pub blacklist: Vec<String>,
+    pub api_core: String,
+    pub noise_strings: Vec<String>,
+}
+
+impl Config {
+    pub fn load() -> Self {
+        let blacklist = load_blacklist("blacklist.txt");
+        let api_core = load_api_core("api_core.txt");
+        let noise_strings = load_noise_strings("noise_strings.txt");
+
+        Config {
+            blacklist,
+            api_core,
+            noise_strings,
+        }
+    }
+}
+
+fn load_blacklist(file_path: &str) -> Vec<String> {
+    let mut blacklist = Vec::new();
+    if let Ok(mut file) = File::open(file_path) {
+        let mut content = String::new();
+        if file.read_to_string(&mut content).is_ok() {
+            blacklist = content
+                .lines()
+                .map(|line| line.trim().to_string())
+                .filter(|line| !line.is_empty())
+                .collect();
+        } else {
+            println!("{} {}", "[*]无法加载黑名单文件:".red(), file_path.red());
+        }
+    } else {
+        println!("{} {}", "[*]无法打开黑名单文件:".red(), file_path.red());
+        // 创建默认黑名单文件
+        let _ = File::create(file_path);
+    }
+    blacklist
+}
+
+fn load_api_core(file_path: &str) -> String {
+    let mut api_core = "/api/".to_string();
+    if let Ok(mut file) = File::open(file_path) {
+        let mut content = String::new();
+        if file.read_to_string(&mut content).is_ok() {
+            api_core = content.trim().to_string();
+            if api_core.is_empty() {
+                api_core = "/api/".to_string();
+            }
+        } else {
+            println!("{} {}", "[*]无法加载api_core文件:".red(), file_path.red());
+        }
+    } else {
+        println!("{} {}", "[*]无法打开api_core文件:".red(), file_path.red());
+        // 创建默认api_core文件
+        if let Ok(mut file) = File::create(file_path) {
+            let _ = file.write_all(api_core.as_bytes());
+        }
+    }
+    api_core
+}
+
+fn load_noise_strings(file_path: &str) -> Vec<String> {
+    let mut noise_strings = Vec::new();
+    if let Ok(mut file) = File::open(file_path) {
+        let mut content = String::new();
+        if file.read_to_string(&mut content).is_ok() {
+            noise_strings = content
+                .lines()
+                .map(|line| line.trim().to_string())
+                .filter(|line| !line.is_empty())
+                .collect();
+        } else {
+            println!("{} {}", "[*]无法加载noise_strings文件:".red(), file_path.red());
+        }
+    } else {
+        println!("{} {}", "[*]无法打开noise_strings文件:".red(), file_path.red());
+        let default_noise = vec![
+            "/>",
+            "><",
+            ">;",
+            "};",
+            "function",
+            "button",
+            "webpack",
+            "chunk",
+            "module",
+            "export",
+            "import",
+            "return",
+            "var",
+            "const",
+            "let",
+            "/a",
+            "/b",
+            "javascript",
+            ")||['//'+",
+            "+",
+            "=",
+            "/t",
+            "xlink",
+            "/!",
+        ];
+        if let Ok(mut file) = File::create(file_path) {
+            let _ = file.write_all(default_noise.join("\n").as_bytes());
+            noise_strings = default_noise.iter().map(|s| s.to_string()).collect();
+        }
+    }
+    noise_strings
+}
@@ -0,0 +1,604 @@
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+use std::sync::{Condvar, Mutex};
+use std::thread;
+use std::time::Instant;
+
+use colored::*;
+use scraper::{Html, Selector};
+use url::Url;
+
+use crate::http::{HttpClient, HttpError, HttpResponse};
+use crate::model::{
+    CollapsedPattern, CrawledPage, Diagnostic, DiscoveredUrl, Finding, Form, InlineScript, Phase,
+    UrlKind,
+};
+use crate::sensitive::{detect_sensitive_info, email_finding, login_redirect_finding, phone_finding};
+use crate::urls::{
+    HTML_EXTENSIONS, Link, canonicalize_url, classify_content_type, classify_url, extract_domain,
+    is_blacklisted, is_static_asset, normalize_url_for_crawl,
+};
+
+pub mod forms;
+pub mod links;
+pub mod scope;
+pub mod scripts;
+pub mod seeds;
+pub mod traps;
+
+pub use forms::extract_forms;
+pub use links::{LinkExtractor, Page, default_extractors};
+pub use scope::Scope;
+pub use scripts::extract_inline_scripts;
+pub use seeds::{Seeds, discover_seeds};
+pub use traps::{Admission, DEFAULT_MAX_VARIANTS, VariantLimiter, url_pattern};
+
+// 爬取过程中共享的状态
+#[derive(Debug, Default)]
+pub struct CrawlState {
+    pub visited: HashSet<String>,
+    pub urls: Vec<DiscoveredUrl>,
+    pub findings: Vec<Finding>,
+    pub domains: HashSet<String>,
+    pub forms: Vec<Form>,
+    pub diagnostics: Vec<Diagnostic>,
+    pub collapsed: Vec<CollapsedPattern>,
+    pub crawled: Vec<CrawledPage>,
+    pub scripts: Vec<InlineScript>,
+    // 请求总数上限耗尽，爬取未完成
+    pub truncated: bool,
+    // URL -> 在 urls 中的位置，避免每个链接都线性查找
+    url_index: HashMap<String, Vec<usize>>,
+}
+
+impl CrawlState {
+    fn push_url(&mut self, discovered: DiscoveredUrl) {
+        self.url_index
+            .entry(discovered.url.clone())
+            .or_default()
+            .push(self.urls.len());
+        self.urls.push(discovered);
+    }
+
+    fn retain_urls(&mut self, keep: impl Fn(&DiscoveredUrl) -> bool) {
+        self.urls.retain(keep);
+        self.url_index.clear();
+        for (index, discovered) in self.urls.iter().enumerate() {
+            self.url_index.entry(discovered.url.clone()).or_default().push(index);
+        }
+    }
+}
+
+// 爬取过程中不变的参数
+pub struct CrawlContext<'a> {
+    pub client: &'a HttpClient,
+    pub blacklist: &'a [String],
+    // 主域名页面最大深度
+    pub max_depth: u8,
+    // 第三方页面最大深度，0 表示不爬取第三方
+    pub third_party_depth: u8,
+    // 并发爬取的工作线程数
+    pub concurrency: usize,
+    // 属于目标的主机范围，范围外的链接视为第三方
+    pub scope: &'a Scope,
+    // 最多抓取的页面数
+    pub max_pages: Option<usize>,
+    // 超过该时间点不再抓取新页面
+    pub deadline: Option<Instant>,
+    // 同一URL模式最多抓取的变体数，0 表示不限制
+    pub max_variants: usize,
+    // 页面链接提取器
+    pub extractors: &'a [Box<dyn LinkExtractor>],
+}
+
+impl CrawlContext<'_> {
+    fn depth_limit(&self, is_third_party: bool) -> u8 {
+        if is_third_party {
+            self.third_party_depth
+        } else {
+            self.max_depth
+        }
+    }
+}
+
+// 待爬取的页面
+#[derive(Debug, Clone)]
+struct CrawlTask {
+    url: String,
+    // 发现该链接的页面
+    source: String,
+    base_url: String,
+    depth: u8,
+    is_third_party: bool,
+}
+
+// 工作队列：active 为正在处理中的任务数，队列为空且 active 为 0 时爬取结束
+#[derive(Default)]
+struct WorkQueue {
+    tasks: VecDeque<CrawlTask>,
+    active: usize,
+}
+
+struct TaskGuard<'a, 'b>(&'a Crawler<'b>);
+
+impl Drop for TaskGuard<'_, '_> {
+    fn drop(&mut self) {
+        self.0.finish_task();
+    }
+}
+
+struct Crawler<'a> {
+    ctx: &'a CrawlContext<'a>,
+    queue: Mutex<WorkQueue>,
+    ready: Condvar,
+    state: Mutex<CrawlState>,
+    // 已发起抓取的页面数
+    pages: AtomicUsize,
+    // 达到上限后不再接受新任务
+    stopped: AtomicBool,
+    // 扩展名无法判断类型的链接及其来源，等待抓取或 HEAD 请求确定类型
+    unclassified: Mutex<HashMap<String, String>>,
+    variants: VariantLimiter,
+}
+
+// 从起始URL与种子URL（URL, 来源）开始并发爬取，返回收集到的结果
+pub fn crawl(ctx: &CrawlContext, start_url: &str, seeds: &[(String, String)]) -> CrawlState {
+    let start_url = canonicalize_url(start_url);
+    let start_url = start_url.as_str();
+    let crawler = Crawler {
+        ctx,
+        queue: Mutex::new(WorkQueue::default()),
+        ready: Condvar::new(),
+        state: Mutex::new(CrawlState::default()),
+        pages: AtomicUsize::new(0),
+        stopped: AtomicBool::new(false),
+        unclassified: Mutex::new(HashMap::new()),
+        variants: VariantLimiter::new(ctx.max_variants),
+    };
+    crawler.push(CrawlTask {
+        url: start_url.to_string(),
+        source: start_url.to_string(),
+        base_url: start_url.to_string(),
+        depth: 1,
+        is_third_party: false,
+    });
+    // 种子与起始页面中的链接同样处理：分类、记录来源并决定是否入队
+    for (url, source) in seeds {
+        let root = CrawlTask {
+            url: source.clone(),
+            source: source.clone(),
+            base_url: start_url.to_string(),
+            depth: 1,
+            is_third_party: false,
+        };
+        crawler.handle_link(&root, url.clone());
+    }
+
+    thread::scope(|scope| {
+        for _ in 0..ctx.concurrency.max(1) {
+            scope.spawn(|| crawler.run_worker());
+        }
+    });
+    crawler.classify_pending();
+
+    let mut state = crawler.state.into_inner().unwrap();
+    // 折叠的变体只在模式汇总中出现，不进入URL列表，也就不会被探测
+    state.retain_urls(|u| u.kind == UrlKind::Api || !crawler.variants.is_skipped(&u.url));
+    state.collapsed = crawler.variants.collapsed();
+    state
+}
+
+impl Crawler<'_> {
+    fn push(&self, task: CrawlTask) {
+        if self.stopped.load(Ordering::SeqCst) {
+            return;
+        }
+        self.queue.lock().unwrap().tasks.push_back(task);
+        self.ready.notify_one();
+    }
+
+    // 达到上限，丢弃剩余任务；只提示一次
+    fn stop(&self, reason: &str, url: &str) {
+        if !self.stopped.swap(true, Ordering::SeqCst) {
+            println!("{} {}", reason.red(), url.red());
+        }
+        self.queue.lock().unwrap().tasks.clear();
+    }
+
+    // 取出下一个任务；没有任务且其他线程也已空闲时返回 None
+    fn next_task(&self) -> Option<CrawlTask> {
+        let mut queue = self.queue.lock().unwrap();
+        loop {
+            if let Some(task) = queue.tasks.pop_front() {
+                queue.active += 1;
+                return Some(task);
+            }
+            if queue.active == 0 {
+                return None;
+            }
+            queue = self.ready.wait(queue).unwrap();
+        }
+    }
+
+    fn finish_task(&self) {
+        let mut queue = self.queue.lock().unwrap_or_else(|e| e.into_inner());
+        queue.active -= 1;
+        if queue.active == 0 && queue.tasks.is_empty() {
+            self.ready.notify_all();
+        }
+    }
+
+    fn run_worker(&self) {
+        while let Some(task) = self.next_task() {
+            // 处理页面时发生 panic 也要释放计数，避免其他线程一直等待
+            let _guard = TaskGuard(self);
+            self.crawl_page(&task);
+        }
+    }
+
+    // 抓取url
+    fn crawl_page(&self, task: &CrawlTask) {
+        let url = task.url.as_str();
+        if task.depth > self.ctx.depth_limit(task.is_third_party) {
+            return;
+        }
+        if is_blacklisted(url, self.ctx.blacklist) {
+            println!("{} {}", "[*]黑名单域名跳过:".red(), url.red());
+            return;
+        }
+        if self.ctx.deadline.is_some_and(|deadline| Instant::now() >= deadline) {
+            self.stop("[*]已达到最长扫描时间，停止爬取:", url);
+            return;
+        }
+        if !self.state.lock().unwrap().visited.insert(url.to_string()) {
+            return;
+        }
+        match self.variants.admit(url) {
+            Admission::Fetch => {}
+            Admission::Collapse(pattern) => {
+                println!("{} {}", "[*]URL模式变体过多，折叠后续变体:".yellow(), pattern.yellow());
+                return;
+            }
+            Admission::Skip => return,
+        }
+        let pages = self.pages.fetch_add(1, Ordering::SeqCst);
+        if self.ctx.max_pages.is_some_and(|max| pages >= max) {
+            self.stop("[*]已达到最大页面数，停止爬取:", url);
+            return;
+        }
+
+        // 单个页面失败只记录，不影响其余页面
+        let mut crawled = CrawledPage::new(url, task.source.as_str());
+        match self.ctx.client.get_chain(url) {
+            Ok((response, redirects)) => {
+                let status = response.status();
+                crawled.status = Some(status.as_u16());
+                crawled.redirect = match redirects.last() {
+                    Some(hop) => Some(hop.location.clone()),
+                    None => redirect_target(&response, url),
+                };
+                crawled.redirects = redirects;
+                if let Some(finding) = crawled
+                    .redirect
+                    .as_deref()
+                    .and_then(|location| login_redirect_finding(url, location, Phase::Crawl))
+                {
+                    println!("{} {}", "[!]跳转到登录页:".purple(), finding.value.purple());
+                    self.state.lock().unwrap().findings.push(finding);
+                }
+                if status.is_success() {
+                    let is_html = is_html_response(&response);
+                    if let Some(kind) = content_type_kind(&response).or(is_html.then_some(UrlKind::Html)) {
+                        self.record_kind(task, url, kind);
+                    }
+                    if is_html && let Err(e) = self.parse_page(task, response) {
+                        println!("{} {} - {}", "读取响应失败:".red(), url, e.to_string().red());
+                        crawled.error_kind = Some(e.kind().to_string());
+                        crawled.error = Some(e.to_string());
+                    }
+                } else {
+                    println!("{} {} - {}", "[*]页面状态异常:".red(), url, status.to_string().red());
+                }
+            }
+            Err(HttpError::BudgetExhausted) => {
+                self.state.lock().unwrap().truncated = true;
+                self.stop("[*]请求总数已达上限，停止爬取:", url);
+                return;
+            }
+            Err(e) => {
+                println!("{} {} - {}", "访问失败:".red(), url, e.to_string().red());
+                crawled.error_kind = Some(e.kind().to_string());
+                crawled.error = Some(e.to_string());
+            }
+        }
+        self.state.lock().unwrap().crawled.push(crawled);
+    }
+
+    // 提取页面中的敏感信息、表单与链接，读取响应体失败时返回错误
+    fn parse_page(&self, task: &CrawlTask, response: HttpResponse) -> Result<(), HttpError> {
+        let url = task.url.as_str();
+        // 相对链接以跳转后的最终地址为准
+        let final_url = response.url().to_string();
+        let canonical_final = canonicalize_url(&final_url);
+        if canonical_final != url {
+            self.state.lock().unwrap().visited.insert(canonical_final);
+        }
+        let html_content = response.text()?;
+        let document = Html::parse_document(&html_content);
+        let page = Page {
+            html: &html_content,
+            document: &document,
+        };
+        let page_base = base_href(&page, &final_url);
+        let findings = detect_sensitive_info(&html_content, &page_base, url, Phase::Crawl);
+        self.state.lock().unwrap().findings.extend(findings);
+        if !task.is_third_party {
+            self.record_forms(task, extract_forms(&page, &page_base, url));
+            let content = extract_inline_scripts(&page);
+            if !content.is_empty() {
+                self.state.lock().unwrap().scripts.push(InlineScript {
+                    source: url.to_string(),
+                    content,
+                });
+            }
+        }
+        for full_url in self.extract_links(&page, &page_base, url) {
+            self.handle_link(task, full_url);
+        }
+        Ok(())
+    }
+
+    // 按响应类型记录URL，覆盖按扩展名得到的类别；表单与JSON接口记录保持不变
+    fn record_kind(&self, task: &CrawlTask, url: &str, kind: UrlKind) {
+        // 与 handle_link 相同，先锁 state 再锁 unclassified，避免链接在两次加锁之间被重新加入待识别列表
+        let mut state = self.state.lock().unwrap();
+        let state = &mut *state;
+        let source = self.unclassified.lock().unwrap().remove(url);
+        let mut recorded = false;
+        for &index in state.url_index.get(url).into_iter().flatten() {
+            let discovered = &mut state.urls[index];
+            if discovered.kind != UrlKind::Api {
+                discovered.kind = kind;
+                recorded = true;
+            }
+        }
+        if !recorded && (source.is_some() || !task.is_third_party) {
+            let source = source.unwrap_or_else(|| task.source.clone());
+            state.push_url(DiscoveredUrl::new(url, kind, source, Phase::Crawl));
+        }
+    }
+
+    // 爬取结束后仍未确定类型的链接：未抓取过的发送 HEAD 请求，其余记为其他
+    fn classify_pending(&self) {
+        let pending: Vec<(String, String)> = self
+            .unclassified
+            .lock()
+            .unwrap()
+            .drain()
+            .filter(|(url, _)| !self.variants.is_skipped(url))
+            .collect();
+        if pending.is_empty() {
+            return;
+        }
+        let next = AtomicUsize::new(0);
+        thread::scope(|scope| {
+            for _ in 0..self.ctx.concurrency.max(1).min(pending.len()) {
+                scope.spawn(|| {
+                    while let Some((url, source)) = pending.get(next.fetch_add(1, Ordering::SeqCst)) {
+                        let kind = self.head_kind(url);
+                        println!(
+                            "{} {} {}",
+                            "[*]HEAD识别类型:".truecolor(255, 250, 205),
+                            kind.as_str().truecolor(255, 250, 205),
+                            url.truecolor(255, 250, 205)
+                        );
+                        self.state
+                            .lock()
+                            .unwrap()
+                            .push_url(DiscoveredUrl::new(url.as_str(), kind, source.as_str(), Phase::Crawl));
+                    }
+                });
+            }
+        });
+    }
+
+    fn head_kind(&self, url: &str) -> UrlKind {
+        let expired = self.ctx.deadline.is_some_and(|deadline| Instant::now() >= deadline);
+        if expired
+            || self.stopped.load(Ordering::SeqCst)
+            || is_blacklisted(url, self.ctx.blacklist)
+            || self.state.lock().unwrap().visited.contains(url)
+        {
+            return UrlKind::Other;
+        }
+        match self.ctx.client.head(url) {
+            Ok(response) if response.status().is_success() => {
+                content_type_kind(&response).unwrap_or(UrlKind::Other)
+            }
+            _ => UrlKind::Other,
+        }
+    }
+
+    // 依次运行所有提取器，同一页面内重复的链接只保留一次；
+    // mailto:/tel: 记为敏感信息，无法解析的值记入诊断信息
+    fn extract_links(&self, page: &Page, base_url: &str, source: &str) -> Vec<String> {
+        let mut seen = HashSet::new();
+        let mut seen_errors = HashSet::new();
+        let mut links = Vec::new();
+        for extractor in self.ctx.extractors {
+            for value in extractor.extract(page) {
+                let link = match normalize_url_for_crawl(&value, base_url) {
+                    Ok(link) => link,
+                    Err(e) => {
+                        if !seen_errors.insert(e.value.clone()) {
+                            continue;
+                        }
+                        println!("{} {}", "[*]无法解析的链接:".red(), e.to_string().red());
+                        self.state
+                            .lock()
+                            .unwrap()
+                            .diagnostics
+                            .push(Diagnostic::new(e.value, e.reason, source));
+                        continue;
+                    }
+                };
+                if !seen.insert(link.clone()) {
+                    continue;
+                }
+                match link {
+                    Link::Http(full_url) => {
+                        println!(
+                            "{} {}",
+                            extractor.label().truecolor(255, 250, 205),
+                            full_url.truecolor(255, 250, 205)
+                        );
+                        links.push(full_url);
+                    }
+                    Link::Email(email) => {
+                        let finding = email_finding(&email, source, Phase::Crawl);
+                        self.state.lock().unwrap().findings.push(finding);
+                    }
+                    Link::Phone(phone) => {
+                        let finding = phone_finding(&phone, source, Phase::Crawl);
+                        self.state.lock().unwrap().findings.push(finding);
+                    }
+                    Link::Skipped(_) => {}
+                }
+            }
+        }
+        links
+    }
+
+    // 保存表单，主域名下的提交地址同时加入接口列表
+    fn record_forms(&self, task: &CrawlTask, forms: Vec<Form>) {
+        let mut state = self.state.lock().unwrap();
+        for mut form in forms {
+            form.action = canonicalize_url(&form.action);
+            println!(
+                "{} {} {} [{}]",
+                "[*]发现表单:".truecolor(255, 250, 205),
+                form.method.truecolor(255, 250, 205),
+                form.action.truecolor(255, 250, 205),
+                form.field_names()
+            );
+            if self.ctx.scope.contains(&task.base_url, &form.action) {
+                state.push_url(DiscoveredUrl::new(
+                    form.action.clone(),
+                    UrlKind::Api,
+                    form.source.as_str(),
+                    Phase::Crawl,
+                ));
+            }
+            state.forms.push(form);
+        }
+    }
+
+    // 记录域名、分类并决定是否继续爬取
+    fn handle_link(&self, task: &CrawlTask, full_url: String) {
+        let full_url = canonicalize_url(&full_url);
+        let Some(domain) = extract_domain(&full_url) else {
+            return;
+        };
+        // 第三方页面只跟随其自身主机的链接
+        let is_third_party_link = if task.is_third_party {
+            extract_domain(&task.base_url).is_none_or(|base_domain| domain != base_domain)
+        } else {
+            !self.ctx.scope.contains(&task.base_url, &full_url)
+        };
+
+        let mut state = self.state.lock().unwrap();
+        state.domains.insert(domain);
+        if is_third_party_link {
+            println!("{} {}", "[!]疑似第三方URL:".purple(), full_url.purple());
+            if !task.is_third_party
+                && self.ctx.third_party_depth > 0
+                && !is_blacklisted(&full_url, self.ctx.blacklist)
+                && !state.visited.contains(&full_url)
+            {
+                drop(state);
+                self.push(CrawlTask {
+                    base_url: full_url.clone(),
+                    source: task.url.clone(),
+                    url: full_url,
+                    depth: 1,
+                    is_third_party: true,
+                });
+            }
+        } else {
+            match classify_url(&full_url) {
+                Some(kind) => {
+                    state.push_url(DiscoveredUrl::new(full_url.clone(), kind, task.url.as_str(), Phase::Crawl))
+                }
+                // 已抓取并按响应确定类型的链接不再重复记录
+                None if state.url_index.contains_key(&full_url) => {}
+                None => {
+                    self.unclassified
+                        .lock()
+                        .unwrap()
+                        .entry(full_url.clone())
+                        .or_insert_with(|| task.url.clone());
+                }
+            }
+            // 除静态资源外都尝试抓取，是否为页面由响应的 Content-Type 决定
+            if !is_static_asset(&full_url)
+                && task.depth < self.ctx.depth_limit(task.is_third_party)
+                && !state.visited.contains(&full_url)
+            {
+                drop(state);
+                self.push(CrawlTask {
+                    url: full_url,
+                    source: task.url.clone(),
+                    base_url: task.base_url.clone(),
+                    depth: task.depth + 1,
+                    is_third_party: task.is_third_party,
+                });
+            }
+        }
+    }
+}
+
+// 3xx 响应的 Location，或自动跳转后与请求地址不同的最终地址
+fn redirect_target(response: &HttpResponse, url: &str) -> Option<String> {
+    if response.status().is_redirection() {
+        return response
+            .headers()
+            .get("location")
+            .and_then(|location| location.to_str().ok())
+            .map(|location| response.url().join(location).map_or(location.to_string(), String::from));
+    }
+    let final_url = response.url().as_str();
+    (canonicalize_url(final_url) != url).then(|| final_url.to_string())
+}
+
+// 页面的 <base href>，相对地址按页面地址解析；没有时即页面地址
+fn base_href(page: &Page, page_url: &str) -> String {
+    let selector = Selector::parse("base[href]").unwrap();
+    page.document
+        .select(&selector)
+        .filter_map(|base| base.value().attr("href"))
+        .map(str::trim)
+        .find(|href| !href.is_empty())
+        .and_then(|href| Url::parse(page_url).ok()?.join(href).ok())
+        .map_or_else(|| page_url.to_string(), String::from)
+}
+
+fn content_type_kind(response: &HttpResponse) -> Option<UrlKind> {
+    response
+        .headers()
+        .get("content-type")
+        .and_then(|v| v.to_str().ok())
+        .map(classify_content_type)
+}
+
+// 按 Content-Type 判断响应是否为页面；没有该响应头时按扩展名判断
+fn is_html_response(response: &HttpResponse) -> bool {
+    match response.headers().get("content-type").and_then(|v| v.to_str().ok()) {
+        Some(content_type) => classify_content_type(content_type) == UrlKind::Html,
+        None => {
+            let path = response.url().path().to_lowercase();
+            path.ends_with('/')
+                || HTML_EXTENSIONS.iter().any(|ext| path.ends_with(ext))
+                || !path.rsplit('/').next().unwrap_or("").contains('.')
+        }
+    }
+}
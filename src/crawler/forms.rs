@@ -0,0 +1,66 @@
+// 表单解析：记录提交地址、方法、编码与字段
+
+use std::time::SystemTime;
+
+use scraper::Selector;
+
+use crate::crawler::Page;
+use crate::model::{Form, FormField};
+use crate::urls::{Link, normalize_url_for_crawl};
+
+const DEFAULT_ENCTYPE: &str = "application/x-www-form-urlencoded";
+
+// 解析页面中的全部表单，source 为页面地址
+pub fn extract_forms(page: &Page, base_url: &str, source: &str) -> Vec<Form> {
+    let form_selector = Selector::parse("form").unwrap();
+    let field_selector = Selector::parse("input[name], select[name], textarea[name], button[name]").unwrap();
+
+    page.document
+        .select(&form_selector)
+        .filter_map(|form| {
+            let element = form.value();
+            // 没有 action 时提交到当前页面；javascript: 等非 http 地址与无法解析的地址跳过
+            let action = match element.attr("action").map(str::trim) {
+                Some(action) if !action.is_empty() => match normalize_url_for_crawl(action, base_url) {
+                    Ok(Link::Http(action)) => action,
+                    _ => return None,
+                },
+                _ => source.to_string(),
+            };
+            let method = element
+                .attr("method")
+                .map(|m| m.trim().to_uppercase())
+                .filter(|m| !m.is_empty())
+                .unwrap_or_else(|| "GET".to_string());
+            let enctype = element
+                .attr("enctype")
+                .map(|e| e.trim().to_lowercase())
+                .filter(|e| !e.is_empty())
+                .unwrap_or_else(|| DEFAULT_ENCTYPE.to_string());
+            let fields = form
+                .select(&field_selector)
+                .filter_map(|field| {
+                    let field = field.value();
+                    // button 与 input 一样记录 type
+                    let kind = match field.name() {
+                        "input" => field.attr("type").unwrap_or("text").to_lowercase(),
+                        "button" => field.attr("type").unwrap_or("submit").to_lowercase(),
+                        tag => tag.to_string(),
+                    };
+                    Some(FormField {
+                        name: field.attr("name")?.to_string(),
+                        kind,
+                    })
+                })
+                .collect();
+            Some(Form {
+                action,
+                method,
+                enctype,
+                fields,
+                source: source.to_string(),
+                timestamp: SystemTime::now(),
+            })
+        })
+        .collect()
+}
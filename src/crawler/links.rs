@@ -0,0 +1,274 @@
+// 页面链接提取器：每种链接来源一个实现，结果统一交给爬虫入队
+
+use regex::Regex;
+use scraper::{Html, Selector};
+
+// 待提取链接的页面，文档只解析一次供所有提取器共用
+pub struct Page<'a> {
+    pub html: &'a str,
+    pub document: &'a Html,
+}
+
+pub trait LinkExtractor: Send + Sync {
+    // 日志前缀
+    fn label(&self) -> &'static str;
+
+    // 返回页面中的原始链接值，由爬虫统一拼接为完整URL
+    fn extract(&self, page: &Page) -> Vec<String>;
+}
+
+// 默认启用的全部提取器
+pub fn default_extractors() -> Vec<Box<dyn LinkExtractor>> {
+    vec![
+        Box::new(RegexSrcHref),
+        Box::new(SrcHrefAttr),
+        Box::new(Iframe),
+        Box::new(FormAction),
+        Box::new(LinkRel),
+        Box::new(MetaRefresh),
+        Box::new(Srcset),
+        Box::new(LazyAttr),
+        Box::new(ObjectEmbed),
+        Box::new(Area),
+        Box::new(InlineStyle),
+    ]
+}
+
+// 取出匹配元素的指定属性
+fn select_attr(page: &Page, selector: &str, attr: &str) -> Vec<String> {
+    let selector = Selector::parse(selector).unwrap();
+    page.document
+        .select(&selector)
+        .filter_map(|element| element.value().attr(attr))
+        .map(|value| value.trim().to_string())
+        .filter(|value| !value.is_empty())
+        .collect()
+}
+
+// srcset 格式："a.png 1x, b.png 2x"，只取每项的URL部分
+fn parse_srcset(srcset: &str) -> Vec<String> {
+    srcset
+        .split(',')
+        .filter_map(|candidate| candidate.split_whitespace().next())
+        .map(|url| url.to_string())
+        .collect()
+}
+
+// 正则匹配 src/href 属性
+pub struct RegexSrcHref;
+
+impl LinkExtractor for RegexSrcHref {
+    fn label(&self) -> &'static str {
+        "[*]正则提取URL:"
+    }
+
+    fn extract(&self, page: &Page) -> Vec<String> {
+        let src_href_regex = Regex::new(r#"(?i)(src|href)=["']([^"']+)["']"#).unwrap();
+        src_href_regex
+            .captures_iter(page.html)
+            .map(|cap| cap[2].to_string())
+            .collect()
+    }
+}
+
+// 带 src 或 href 属性的元素
+pub struct SrcHrefAttr;
+
+impl LinkExtractor for SrcHrefAttr {
+    fn label(&self) -> &'static str {
+        "[*]目标提取URL:"
+    }
+
+    fn extract(&self, page: &Page) -> Vec<String> {
+        let mut links = select_attr(page, "[src]", "src");
+        links.extend(select_attr(page, "[href]", "href"));
+        links
+    }
+}
+
+pub struct Iframe;
+
+impl LinkExtractor for Iframe {
+    fn label(&self) -> &'static str {
+        "[*]iframe提取URL:"
+    }
+
+    fn extract(&self, page: &Page) -> Vec<String> {
+        let mut links = select_attr(page, "iframe[src]", "src");
+        links.extend(select_attr(page, "frame[src]", "src"));
+        links
+    }
+}
+
+// 表单提交地址
+pub struct FormAction;
+
+impl LinkExtractor for FormAction {
+    fn label(&self) -> &'static str {
+        "[*]表单提取URL:"
+    }
+
+    fn extract(&self, page: &Page) -> Vec<String> {
+        let mut links = select_attr(page, "form[action]", "action");
+        links.extend(select_attr(page, "[formaction]", "formaction"));
+        links
+    }
+}
+
+// <link rel=...>：canonical、alternate、preload、manifest 等
+pub struct LinkRel;
+
+impl LinkExtractor for LinkRel {
+    fn label(&self) -> &'static str {
+        "[*]link提取URL:"
+    }
+
+    fn extract(&self, page: &Page) -> Vec<String> {
+        let mut links = select_attr(page, "link[rel][href]", "href");
+        for srcset in select_attr(page, "link[imagesrcset]", "imagesrcset") {
+            links.extend(parse_srcset(&srcset));
+        }
+        links
+    }
+}
+
+// <meta http-equiv="refresh" content="0; url=/next">
+pub struct MetaRefresh;
+
+impl LinkExtractor for MetaRefresh {
+    fn label(&self) -> &'static str {
+        "[*]meta跳转URL:"
+    }
+
+    fn extract(&self, page: &Page) -> Vec<String> {
+        let selector = Selector::parse("meta[http-equiv][content]").unwrap();
+        page.document
+            .select(&selector)
+            .filter(|meta| {
+                meta.value()
+                    .attr("http-equiv")
+                    .is_some_and(|v| v.trim().eq_ignore_ascii_case("refresh"))
+            })
+            .filter_map(|meta| meta.value().attr("content"))
+            .filter_map(|content| {
+                let (_, target) = content.split_once(';')?;
+                let target = target.trim();
+                let target = match target.get(..4) {
+                    Some(prefix) if prefix.eq_ignore_ascii_case("url=") => &target[4..],
+                    _ => target,
+                };
+                let target = target.trim().trim_matches(|c| c == '"' || c == '\'');
+                (!target.is_empty()).then(|| target.to_string())
+            })
+            .collect()
+    }
+}
+
+// 响应式图片 srcset
+pub struct Srcset;
+
+impl LinkExtractor for Srcset {
+    fn label(&self) -> &'static str {
+        "[*]srcset提取URL:"
+    }
+
+    fn extract(&self, page: &Page) -> Vec<String> {
+        select_attr(page, "[srcset]", "srcset")
+            .iter()
+            .flat_map(|srcset| parse_srcset(srcset))
+            .collect()
+    }
+}
+
+// 懒加载常用的属性
+const LAZY_ATTRS: &[&str] = &[
+    "data-src",
+    "data-original",
+    "data-lazy-src",
+    "data-lazy",
+    "data-url",
+    "data-href",
+    "data-bg",
+    "data-background",
+    "lowsrc",
+];
+
+pub struct LazyAttr;
+
+impl LinkExtractor for LazyAttr {
+    fn label(&self) -> &'static str {
+        "[*]懒加载提取URL:"
+    }
+
+    fn extract(&self, page: &Page) -> Vec<String> {
+        let mut links = Vec::new();
+        for attr in LAZY_ATTRS {
+            links.extend(select_attr(page, &format!("[{}]", attr), attr));
+        }
+        for srcset in select_attr(page, "[data-srcset]", "data-srcset") {
+            links.extend(parse_srcset(&srcset));
+        }
+        links
+    }
+}
+
+// 插件内容：object、embed 与 param
+pub struct ObjectEmbed;
+
+impl LinkExtractor for ObjectEmbed {
+    fn label(&self) -> &'static str {
+        "[*]object/embed提取URL:"
+    }
+
+    fn extract(&self, page: &Page) -> Vec<String> {
+        let mut links = select_attr(page, "object[data]", "data");
+        links.extend(select_attr(page, "embed[src]", "src"));
+        let selector = Selector::parse("param[name][value]").unwrap();
+        links.extend(
+            page.document
+                .select(&selector)
+                .filter(|param| {
+                    param.value().attr("name").is_some_and(|name| {
+                        ["movie", "src", "url"].iter().any(|n| name.eq_ignore_ascii_case(n))
+                    })
+                })
+                .filter_map(|param| param.value().attr("value"))
+                .map(|value| value.trim().to_string()),
+        );
+        links
+    }
+}
+
+// 图片热区
+pub struct Area;
+
+impl LinkExtractor for Area {
+    fn label(&self) -> &'static str {
+        "[*]area提取URL:"
+    }
+
+    fn extract(&self, page: &Page) -> Vec<String> {
+        select_attr(page, "area[href]", "href")
+    }
+}
+
+// style 属性与 <style> 中的 url()
+pub struct InlineStyle;
+
+impl LinkExtractor for InlineStyle {
+    fn label(&self) -> &'static str {
+        "[*]样式提取URL:"
+    }
+
+    fn extract(&self, page: &Page) -> Vec<String> {
+        let url_regex = Regex::new(r#"url\(\s*["']?([^"')]+?)["']?\s*\)"#).unwrap();
+        let mut styles = select_attr(page, "[style]", "style");
+        let selector = Selector::parse("style").unwrap();
+        styles.extend(page.document.select(&selector).map(|style| style.text().collect()));
+        styles
+            .iter()
+            .flat_map(|style| url_regex.captures_iter(style))
+            .map(|cap| cap[1].trim().to_string())
+            .collect()
+    }
+}
@@ -0,0 +1,58 @@
+// 爬取范围：判断链接是否属于扫描目标
+
+use url::Url;
+
+use crate::urls::registrable_domain;
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub enum Scope {
+    // 仅与目标相同的主机（含端口）
+    #[default]
+    Host,
+    // 目标可注册域名下的全部子域名
+    Domain,
+    // 目标主机与显式列出的主机，可带端口
+    Hosts(Vec<String>),
+}
+
+impl Scope {
+    // url 是否在以 target 为目标的扫描范围内
+    pub fn contains(&self, target: &str, url: &str) -> bool {
+        let (Ok(target), Ok(url)) = (Url::parse(target), Url::parse(url)) else {
+            return false;
+        };
+        let (Some(target_host), Some(host)) = (target.host_str(), url.host_str()) else {
+            return false;
+        };
+        let same_host =
+            target_host == host && target.port_or_known_default() == url.port_or_known_default();
+        match self {
+            Scope::Host => same_host,
+            Scope::Domain => {
+                let domain = registrable_domain(target_host);
+                host == domain || host.ends_with(&format!(".{}", domain))
+            }
+            Scope::Hosts(hosts) => {
+                let host_port = format!("{}:{}", host, url.port_or_known_default().unwrap_or_default());
+                same_host
+                    || hosts
+                        .iter()
+                        .any(|h| h.eq_ignore_ascii_case(host) || h.eq_ignore_ascii_case(&host_port))
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn domain_scope_stops_at_public_suffix() {
+        assert!(Scope::Domain.contains("http://www.example.com/", "https://api.example.com/x"));
+        assert!(Scope::Domain.contains("http://a.example.co.za/", "http://example.co.za/"));
+        assert!(!Scope::Domain.contains("http://a.github.io/", "http://b.github.io/x"));
+        assert!(!Scope::Domain.contains("http://a.example.co.za/", "http://other.co.za/"));
+        assert!(!Scope::Domain.contains("http://www.example.com/", "http://example.com.evil.net/"));
+    }
+}
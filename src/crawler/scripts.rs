@@ -0,0 +1,40 @@
+// 内联脚本：<script> 代码块、onclick 等事件处理器与 javascript: 链接
+
+use scraper::Selector;
+
+use crate::crawler::Page;
+
+// 页面中的全部内联脚本代码，每段一行拼接
+pub fn extract_inline_scripts(page: &Page) -> String {
+    let mut scripts = Vec::new();
+
+    // 带 src 的外部脚本由 JS 提取阶段下载
+    let script_selector = Selector::parse("script:not([src])").unwrap();
+    for script in page.document.select(&script_selector) {
+        let code: String = script.text().collect();
+        if !code.trim().is_empty() {
+            scripts.push(code);
+        }
+    }
+
+    let all_selector = Selector::parse("*").unwrap();
+    for element in page.document.select(&all_selector) {
+        for (name, value) in element.value().attrs() {
+            let value = value.trim();
+            if name.len() > 2 && name.starts_with("on") {
+                scripts.push(value.to_string());
+            } else if value
+                .get(..11)
+                .is_some_and(|scheme| scheme.eq_ignore_ascii_case("javascript:"))
+            {
+                // href="javascript:..." 中的代码可能经过URL编码
+                let code = &value[11..];
+                let code = urlencoding::decode(code).map_or(code.to_string(), |c| c.into_owned());
+                scripts.push(code);
+            }
+        }
+    }
+
+    scripts.retain(|code| !code.trim().is_empty());
+    scripts.join("\n")
+}
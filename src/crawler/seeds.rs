@@ -0,0 +1,175 @@
+// 从 robots.txt 与 sitemap.xml 获取额外的种子URL
+
+use std::collections::HashSet;
+use std::io::Read;
+
+use colored::*;
+use flate2::read::GzDecoder;
+use regex::Regex;
+use url::Url;
+
+use crate::crawler::Scope;
+use crate::http::{HttpClient, HttpError};
+use crate::model::{Finding, Phase};
+
+// 最多读取的 sitemap 数量，防止 sitemap 索引互相引用或过多
+const MAX_SITEMAPS: usize = 20;
+
+// 种子URL及其来源（robots.txt 或 sitemap 地址）
+#[derive(Debug, Default)]
+pub struct Seeds {
+    pub urls: Vec<(String, String)>,
+    pub findings: Vec<Finding>,
+}
+
+// robots.txt 解析结果
+#[derive(Debug, Default)]
+struct Robots {
+    allow: Vec<String>,
+    disallow: Vec<String>,
+    sitemaps: Vec<String>,
+}
+
+// robots.txt 的 Disallow 路径记为敏感信息，disallow_seeds 开启时同时作为种子；
+// 只读取扫描范围内的 sitemap
+pub fn discover_seeds(client: &HttpClient, base_url: &str, scope: &Scope, disallow_seeds: bool) -> Seeds {
+    let mut seeds = Seeds::default();
+    let Ok(origin) = Url::parse(base_url) else {
+        return seeds;
+    };
+    let robots_url = origin.join("/robots.txt").map(String::from).unwrap_or_default();
+
+    let mut sitemaps = Vec::new();
+    match fetch(client, &robots_url) {
+        Ok(Some(body)) => {
+            let robots = parse_robots(&String::from_utf8_lossy(&body));
+            for path in &robots.allow {
+                if let Some(url) = resolve_rule(&origin, path) {
+                    seeds.urls.push((url, robots_url.clone()));
+                }
+            }
+            for path in &robots.disallow {
+                if let Some(url) = resolve_rule(&origin, path) {
+                    println!("{} {}", "[*]robots禁止路径:".truecolor(255, 215, 0), url.truecolor(255, 215, 0));
+                    seeds
+                        .findings
+                        .push(Finding::new("robots禁止路径", url.clone(), robots_url.as_str(), Phase::Crawl));
+                    if disallow_seeds {
+                        seeds.urls.push((url, robots_url.clone()));
+                    }
+                }
+            }
+            sitemaps.extend(robots.sitemaps);
+        }
+        Ok(None) => {}
+        Err(HttpError::BudgetExhausted) => return seeds,
+        Err(e) => println!("{} {} - {}", "[*]获取robots.txt失败:".red(), robots_url, e.to_string().red()),
+    }
+    if let Ok(default_sitemap) = origin.join("/sitemap.xml") {
+        sitemaps.push(default_sitemap.to_string());
+    }
+
+    // 广度优先读取 sitemap，索引中的子 sitemap 追加到队尾
+    let loc_re = Regex::new(r"(?is)<loc>\s*(.*?)\s*</loc>").unwrap();
+    let mut seen = HashSet::new();
+    let mut index = 0;
+    while index < sitemaps.len() && seen.len() < MAX_SITEMAPS {
+        let sitemap_url = sitemaps[index].clone();
+        index += 1;
+        if !seen.insert(sitemap_url.clone()) {
+            continue;
+        }
+        if !scope.contains(base_url, &sitemap_url) {
+            println!("{} {}", "[*]跳过范围外的sitemap:".red(), sitemap_url.red());
+            continue;
+        }
+        let body = match fetch(client, &sitemap_url) {
+            Ok(Some(body)) => body,
+            Ok(None) => continue,
+            Err(HttpError::BudgetExhausted) => break,
+            Err(e) => {
+                println!("{} {} - {}", "[*]获取sitemap失败:".red(), sitemap_url, e.to_string().red());
+                continue;
+            }
+        };
+        let Some(xml) = decode_sitemap(&body) else {
+            println!("{} {}", "[*]sitemap解压失败:".red(), sitemap_url.red());
+            continue;
+        };
+        let is_index = xml.contains("<sitemapindex");
+        for cap in loc_re.captures_iter(&xml) {
+            let loc = unescape_xml(&cap[1]);
+            if is_index {
+                sitemaps.push(loc);
+            } else {
+                seeds.urls.push((loc, sitemap_url.clone()));
+            }
+        }
+    }
+
+    println!(
+        "{} {}",
+        "[*]robots/sitemap种子URL数:".truecolor(255, 215, 0),
+        seeds.urls.len().to_string().truecolor(255, 215, 0)
+    );
+    seeds
+}
+
+// 成功时返回响应体，非 2xx 返回 None
+fn fetch(client: &HttpClient, url: &str) -> Result<Option<Vec<u8>>, HttpError> {
+    let response = client.get(url)?;
+    if !response.status().is_success() {
+        return Ok(None);
+    }
+    Ok(Some(response.bytes()?))
+}
+
+fn parse_robots(content: &str) -> Robots {
+    let mut robots = Robots::default();
+    for line in content.lines() {
+        let line = line.split('#').next().unwrap_or("").trim();
+        let Some((key, value)) = line.split_once(':') else {
+            continue;
+        };
+        let value = value.trim();
+        if value.is_empty() {
+            continue;
+        }
+        match key.trim().to_lowercase().as_str() {
+            "allow" => robots.allow.push(value.to_string()),
+            "disallow" => robots.disallow.push(value.to_string()),
+            "sitemap" => robots.sitemaps.push(value.to_string()),
+            _ => {}
+        }
+    }
+    robots
+}
+
+// 规则路径转为完整URL：通配符只保留前缀，"/" 本身忽略
+fn resolve_rule(origin: &Url, path: &str) -> Option<String> {
+    let path = path.split('*').next()?.trim_end_matches('$');
+    if !path.starts_with('/') || path == "/" {
+        return None;
+    }
+    origin.join(path).ok().map(String::from)
+}
+
+// gzip 格式的 sitemap 按魔数识别，与扩展名无关
+fn decode_sitemap(body: &[u8]) -> Option<String> {
+    if body.starts_with(&[0x1f, 0x8b]) {
+        let mut xml = String::new();
+        GzDecoder::new(body).read_to_string(&mut xml).ok()?;
+        Some(xml)
+    } else {
+        Some(String::from_utf8_lossy(body).into_owned())
+    }
+}
+
+fn unescape_xml(value: &str) -> String {
+    value
+        .replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&apos;", "'")
+        .replace("&amp;", "&")
+}
@@ -0,0 +1,130 @@
+// 爬虫陷阱：日历、分页、会话ID等只有参数值不同或路径段重复的URL归为同一模式，限制每个模式的抓取数
+
+use std::collections::{BTreeSet, HashMap, HashSet};
+use std::sync::Mutex;
+use std::time::SystemTime;
+
+use url::Url;
+
+use crate::model::CollapsedPattern;
+
+// 每个模式默认最多抓取的变体数
+pub const DEFAULT_MAX_VARIANTS: usize = 20;
+
+// URL模式：参数值替换为 *，参数名排序去重；路径中重复出现的段折叠为 **
+// 例如 /cal?year=2024&month=5 -> /cal?month=*&year=*，/a/b/a/b/ -> /a/b/**
+pub fn url_pattern(url: &str) -> String {
+    let Ok(parsed) = Url::parse(url) else {
+        return url.to_string();
+    };
+    let mut pattern = format!("{}://{}", parsed.scheme(), parsed.host_str().unwrap_or_default());
+    if let Some(port) = parsed.port() {
+        pattern.push_str(&format!(":{}", port));
+    }
+
+    let mut seen = HashSet::new();
+    let mut repeated = false;
+    for segment in parsed.path_segments().into_iter().flatten() {
+        if segment.is_empty() {
+            continue;
+        }
+        if !seen.insert(segment) {
+            repeated = true;
+            continue;
+        }
+        pattern.push('/');
+        pattern.push_str(segment);
+    }
+    if repeated {
+        pattern.push_str("/**");
+    } else if parsed.path().ends_with('/') {
+        pattern.push('/');
+    }
+
+    let names: BTreeSet<String> = parsed.query_pairs().map(|(name, _)| name.into_owned()).collect();
+    if !names.is_empty() {
+        let query: Vec<String> = names.iter().map(|name| format!("{}=*", name)).collect();
+        pattern.push('?');
+        pattern.push_str(&query.join("&"));
+    }
+    pattern
+}
+
+#[derive(Debug, Default)]
+struct Variants {
+    fetched: usize,
+    skipped: usize,
+    // 第一个被跳过的URL
+    example: Option<String>,
+}
+
+// 单个URL的抓取判定
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Admission {
+    Fetch,
+    // 模式第一次超出上限，附带所属模式
+    Collapse(String),
+    // 模式已折叠，不再单独提示
+    Skip,
+}
+
+// 统计各模式已抓取的变体数，max_variants 为 0 时不限制
+#[derive(Debug, Default)]
+pub struct VariantLimiter {
+    max_variants: usize,
+    patterns: Mutex<HashMap<String, Variants>>,
+    // 被跳过的URL，不再做 HEAD 识别、记录或探测
+    skipped: Mutex<HashSet<String>>,
+}
+
+impl VariantLimiter {
+    pub fn new(max_variants: usize) -> Self {
+        VariantLimiter {
+            max_variants,
+            patterns: Mutex::new(HashMap::new()),
+            skipped: Mutex::new(HashSet::new()),
+        }
+    }
+
+    // 是否允许抓取该URL；超出上限时记为跳过
+    pub fn admit(&self, url: &str) -> Admission {
+        let pattern = url_pattern(url);
+        let mut patterns = self.patterns.lock().unwrap();
+        let variants = patterns.entry(pattern.clone()).or_default();
+        if self.max_variants == 0 || variants.fetched < self.max_variants {
+            variants.fetched += 1;
+            return Admission::Fetch;
+        }
+        variants.skipped += 1;
+        self.skipped.lock().unwrap().insert(url.to_string());
+        if variants.example.is_some() {
+            return Admission::Skip;
+        }
+        variants.example = Some(url.to_string());
+        Admission::Collapse(pattern)
+    }
+
+    pub fn is_skipped(&self, url: &str) -> bool {
+        self.skipped.lock().unwrap().contains(url)
+    }
+
+    // 有变体被跳过的模式
+    pub fn collapsed(self) -> Vec<CollapsedPattern> {
+        let mut collapsed: Vec<CollapsedPattern> = self
+            .patterns
+            .into_inner()
+            .unwrap()
+            .into_iter()
+            .filter(|(_, variants)| variants.skipped > 0)
+            .map(|(pattern, variants)| CollapsedPattern {
+                pattern,
+                fetched: variants.fetched,
+                skipped: variants.skipped,
+                example: variants.example.unwrap_or_default(),
+                timestamp: SystemTime::now(),
+            })
+            .collect();
+        collapsed.sort_by(|a, b| a.pattern.cmp(&b.pattern));
+        collapsed
+    }
+}
@@ -0,0 +1,84 @@
+// 脚本依赖：import()、静态 import、importScripts、Worker、Service Worker 与动态创建的 <script>
+
+use colored::*;
+use regex::Regex;
+use url::Url;
+
+// 脚本引用的其他脚本地址
+// 模块导入与 importScripts 相对脚本地址解析；Worker、Service Worker 与 <script> 相对页面地址解析
+pub fn discover_dependencies(content: &str, script_url: &str, page_url: &str) -> Vec<String> {
+    let (Ok(script_url), Ok(page_url)) = (Url::parse(script_url), Url::parse(page_url)) else {
+        return Vec::new();
+    };
+    let mut references: Vec<(String, &Url)> = Vec::new();
+
+    // import("./a.js")、import "./a.js"、import x from "./a.js"、export * from "./a.js"
+    let module_re = Regex::new(
+        r#"(?:\bimport\s*\(\s*|(?:^|[;\s}])import\s*(?:[\w*{}\s,$]+from\s*)?|\bexport\s*[\w*{}\s,$]*from\s*)["'`]([^"'`\s]+)["'`]"#,
+    )
+    .unwrap();
+    for cap in module_re.captures_iter(content) {
+        // 裸模块名（如 react）由打包工具解析，不是地址
+        if is_path_like(&cap[1]) {
+            references.push((cap[1].to_string(), &script_url));
+        }
+    }
+
+    let string_re = Regex::new(r#"["'`]([^"'`\s]+)["'`]"#).unwrap();
+    let import_scripts_re = Regex::new(r#"\bimportScripts\s*\(([^)]*)\)"#).unwrap();
+    for cap in import_scripts_re.captures_iter(content) {
+        for arg in string_re.captures_iter(&cap[1]) {
+            references.push((arg[1].to_string(), &script_url));
+        }
+    }
+
+    // new Worker(new URL("./w.js", import.meta.url)) 相对脚本地址
+    let meta_url_re =
+        Regex::new(r#"new\s+URL\s*\(\s*["'`]([^"'`\s]+)["'`]\s*,\s*import\.meta\.url"#).unwrap();
+    for cap in meta_url_re.captures_iter(content) {
+        references.push((cap[1].to_string(), &script_url));
+    }
+
+    let page_re = Regex::new(
+        r#"(?:new\s+(?:Shared)?Worker|serviceWorker\s*\.\s*register)\s*\(\s*["'`]([^"'`\s]+)["'`]"#,
+    )
+    .unwrap();
+    for cap in page_re.captures_iter(content) {
+        references.push((cap[1].to_string(), &page_url));
+    }
+
+    // document.createElement("script") 之后最近的 .src = "..."
+    let create_re = Regex::new(r#"createElement\s*\(\s*["'`]script["'`]\s*\)"#).unwrap();
+    let src_re = Regex::new(r#"\.src\s*=\s*["'`]([^"'`\s]+)["'`]"#).unwrap();
+    for m in create_re.find_iter(content) {
+        let window: String = content[m.end()..].chars().take(500).collect();
+        if let Some(cap) = src_re.captures(&window) {
+            references.push((cap[1].to_string(), &page_url));
+        }
+    }
+
+    let mut dependencies: Vec<String> = references
+        .into_iter()
+        .filter(|(reference, _)| {
+            !reference.contains("${") && !reference.starts_with("data:") && !reference.starts_with("blob:")
+        })
+        .filter_map(|(reference, base)| base.join(&reference).ok())
+        .filter(|url| matches!(url.scheme(), "http" | "https"))
+        .map(String::from)
+        .collect();
+    dependencies.sort();
+    dependencies.dedup();
+    for dependency in &dependencies {
+        println!("{} {}", "[*]发现依赖脚本:".cyan(), dependency.cyan());
+    }
+    dependencies
+}
+
+// 相对路径、绝对路径或完整URL
+fn is_path_like(reference: &str) -> bool {
+    reference.starts_with("./")
+        || reference.starts_with("../")
+        || reference.starts_with('/')
+        || reference.starts_with("http://")
+        || reference.starts_with("https://")
+}
@@ -0,0 +1,279 @@
+use std::collections::HashMap;
+use std::fmt;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Condvar, Mutex};
+use std::thread;
+use std::time::{Duration, Instant};
+
+use rand::Rng;
+use reqwest::blocking::{Client, Response};
+use reqwest::header::HeaderMap;
+use reqwest::{Method, StatusCode, Url};
+
+use crate::model::RedirectHop;
+
+// 逐跳跟随时最多跟随的跳转次数
+const MAX_REDIRECTS: usize = 10;
+
+// 限速的最小值（每秒请求数），更小的值按此处理，避免请求间隔溢出
+pub const MIN_RATE_LIMIT: f64 = 0.001;
+
+// 请求节流与跳转参数，所有阶段共用
+#[derive(Debug, Clone, Default)]
+pub struct HttpOptions {
+    // 每个主机每秒最多请求数
+    pub rate_limit: Option<f64>,
+    // 每次请求前额外随机等待的最长时间
+    pub jitter: Duration,
+    // 每个主机同时进行中的最大请求数
+    pub max_per_host: Option<usize>,
+    // 整个扫描的请求总数上限
+    pub max_requests: Option<usize>,
+    // 底层客户端不自动跟随跳转，由 HttpClient 逐跳跟随，get_chain 返回跳转链
+    pub no_follow_redirects: bool,
+}
+
+#[derive(Debug)]
+pub enum HttpError {
+    // 已达到请求总数上限
+    BudgetExhausted,
+    Request(reqwest::Error),
+}
+
+impl fmt::Display for HttpError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            HttpError::BudgetExhausted => write!(f, "请求总数已达上限"),
+            HttpError::Request(e) => write!(f, "{}", e),
+        }
+    }
+}
+
+impl HttpError {
+    // 错误类别，记录在报告中
+    pub fn kind(&self) -> &'static str {
+        match self {
+            HttpError::BudgetExhausted => "budget",
+            HttpError::Request(e) if e.is_timeout() => "timeout",
+            HttpError::Request(e) if e.is_connect() => "connect",
+            HttpError::Request(e) if e.is_redirect() => "redirect",
+            HttpError::Request(e) if e.is_body() => "body",
+            HttpError::Request(e) if e.is_decode() => "decode",
+            HttpError::Request(_) => "request",
+        }
+    }
+}
+
+impl std::error::Error for HttpError {}
+
+impl From<reqwest::Error> for HttpError {
+    fn from(e: reqwest::Error) -> Self {
+        HttpError::Request(e)
+    }
+}
+
+#[derive(Debug)]
+struct HostState {
+    next_slot: Instant,
+    in_flight: usize,
+}
+
+// 带节流的HTTP客户端：按主机限速、随机抖动、限制并发与请求总数
+pub struct HttpClient {
+    client: Client,
+    options: HttpOptions,
+    hosts: Mutex<HashMap<String, HostState>>,
+    host_released: Condvar,
+    sent: AtomicUsize,
+}
+
+// 响应包装，持有主机并发名额直到响应体读取完毕
+pub struct HttpResponse<'a> {
+    inner: Response,
+    _permit: HostPermit<'a>,
+}
+
+struct HostPermit<'a> {
+    client: &'a HttpClient,
+    host: String,
+}
+
+impl Drop for HostPermit<'_> {
+    fn drop(&mut self) {
+        self.client.release(&self.host);
+    }
+}
+
+impl HttpResponse<'_> {
+    pub fn status(&self) -> StatusCode {
+        self.inner.status()
+    }
+
+    pub fn headers(&self) -> &HeaderMap {
+        self.inner.headers()
+    }
+
+    pub fn url(&self) -> &Url {
+        self.inner.url()
+    }
+
+    // Content-Length 响应头
+    pub fn content_length(&self) -> Option<u64> {
+        self.inner
+            .headers()
+            .get("content-length")
+            .and_then(|v| v.to_str().ok())
+            .and_then(|v| v.parse().ok())
+    }
+
+    pub fn text(self) -> Result<String, HttpError> {
+        Ok(self.inner.text()?)
+    }
+
+    pub fn bytes(self) -> Result<Vec<u8>, HttpError> {
+        Ok(self.inner.bytes()?.to_vec())
+    }
+}
+
+impl HttpClient {
+    pub fn new(client: Client, options: HttpOptions) -> Self {
+        HttpClient {
+            client,
+            options,
+            hosts: Mutex::new(HashMap::new()),
+            host_released: Condvar::new(),
+            sent: AtomicUsize::new(0),
+        }
+    }
+
+    pub fn options(&self) -> &HttpOptions {
+        &self.options
+    }
+
+    // 已发出的请求数
+    pub fn requests_sent(&self) -> usize {
+        self.sent.load(Ordering::SeqCst)
+    }
+
+    // 返回最终响应：不自动跟随跳转时在此逐跳跟随，JS、source map、robots 等资源不会因 3xx 丢失
+    pub fn get(&self, url: &str) -> Result<HttpResponse<'_>, HttpError> {
+        Ok(self.follow(Method::GET, url)?.0)
+    }
+
+    pub fn head(&self, url: &str) -> Result<HttpResponse<'_>, HttpError> {
+        Ok(self.follow(Method::HEAD, url)?.0)
+    }
+
+    // GET 并返回跳转链；自动跟随跳转时跳转链为空
+    // 逐跳跟随时遇到循环或超过 MAX_REDIRECTS 次，返回最后一个 3xx 响应
+    pub fn get_chain(&self, url: &str) -> Result<(HttpResponse<'_>, Vec<RedirectHop>), HttpError> {
+        self.follow(Method::GET, url)
+    }
+
+    fn follow(&self, method: Method, url: &str) -> Result<(HttpResponse<'_>, Vec<RedirectHop>), HttpError> {
+        let mut response = self.send(method.clone(), url)?;
+        let mut hops: Vec<RedirectHop> = Vec::new();
+        if !self.options.no_follow_redirects {
+            return Ok((response, hops));
+        }
+        while response.status().is_redirection() && hops.len() < MAX_REDIRECTS {
+            let Some(location) = response
+                .headers()
+                .get("location")
+                .and_then(|v| v.to_str().ok())
+                .and_then(|v| response.url().join(v).ok())
+            else {
+                break;
+            };
+            let location = location.to_string();
+            hops.push(RedirectHop {
+                url: response.url().to_string(),
+                status: response.status().as_u16(),
+                location: location.clone(),
+                content_length: response.content_length(),
+            });
+            if hops.iter().any(|hop| hop.url == location) {
+                break;
+            }
+            // 先释放上一个响应的主机名额
+            drop(response);
+            response = self.send(method.clone(), &location)?;
+        }
+        Ok((response, hops))
+    }
+
+    fn send(&self, method: Method, url: &str) -> Result<HttpResponse<'_>, HttpError> {
+        let sent = self.sent.fetch_add(1, Ordering::SeqCst);
+        if self.options.max_requests.is_some_and(|max| sent >= max) {
+            return Err(HttpError::BudgetExhausted);
+        }
+
+        let host = host_key(url);
+        let permit = self.acquire(&host);
+        let inner = self.client.request(method, url).send()?;
+        Ok(HttpResponse {
+            inner,
+            _permit: permit,
+        })
+    }
+
+    // 等待主机并发名额与限速时间片，返回名额
+    fn acquire(&self, host: &str) -> HostPermit<'_> {
+        let mut hosts = self.hosts.lock().unwrap();
+        loop {
+            let state = hosts.entry(host.to_string()).or_insert_with(|| HostState {
+                next_slot: Instant::now(),
+                in_flight: 0,
+            });
+            match self.options.max_per_host {
+                Some(max) if state.in_flight >= max => {
+                    hosts = self.host_released.wait(hosts).unwrap();
+                }
+                _ => break,
+            }
+        }
+
+        let state = hosts.get_mut(host).unwrap();
+        state.in_flight += 1;
+        let now = Instant::now();
+        let mut wait = state.next_slot.saturating_duration_since(now);
+        if let Some(rate) = self.options.rate_limit.filter(|r| *r > 0.0) {
+            let rate = rate.max(MIN_RATE_LIMIT);
+            state.next_slot = state.next_slot.max(now) + Duration::from_secs_f64(1.0 / rate);
+        }
+        drop(hosts);
+
+        if !self.options.jitter.is_zero() {
+            let max_ms = self.options.jitter.as_millis() as u64;
+            wait += Duration::from_millis(rand::thread_rng().gen_range(0..=max_ms));
+        }
+        if !wait.is_zero() {
+            thread::sleep(wait);
+        }
+
+        HostPermit {
+            client: self,
+            host: host.to_string(),
+        }
+    }
+
+    fn release(&self, host: &str) {
+        let mut hosts = self.hosts.lock().unwrap_or_else(|e| e.into_inner());
+        if let Some(state) = hosts.get_mut(host) {
+            state.in_flight = state.in_flight.saturating_sub(1);
+        }
+        self.host_released.notify_all();
+    }
+}
+
+// 限速按 主机:端口 区分
+fn host_key(url: &str) -> String {
+    match Url::parse(url) {
+        Ok(parsed) => format!(
+            "{}:{}",
+            parsed.host_str().unwrap_or_default(),
+            parsed.port_or_known_default().unwrap_or_default()
+        ),
+        Err(_) => url.to_string(),
+    }
+}
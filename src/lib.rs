@@ -0,0 +1,25 @@
+// web_scraper：爬取页面 -> 提取JS接口 -> 探测存活 -> 输出报告
+
+pub mod api;
+pub mod batch;
+pub mod chunks;
+pub mod config;
+pub mod crawler;
+pub mod deps;
+pub mod http;
+pub mod model;
+pub mod output;
+pub mod scanner;
+pub mod sensitive;
+pub mod sourcemap;
+pub mod urls;
+
+pub use config::Config;
+pub use model::{
+    CollapsedPattern, CrawledPage, Diagnostic, DiscoveredUrl, Finding, Form, FormField, InlineScript,
+    ProbeResult, RedirectHop, ScanReport, UrlKind,
+};
+pub use scanner::{ScanOptions, Scanner};
+
+pub type Error = Box<dyn std::error::Error + Send + Sync>;
+pub type Result<T> = std::result::Result<T, Error>;
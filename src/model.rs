@@ -0,0 +1,435 @@
+// 扫描结果数据模型，所有输出格式都基于这些结构
+
+use std::collections::HashSet;
+use std::time::SystemTime;
+
+use serde::{Deserialize, Serialize};
+
+// 产生结果的扫描阶段
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Phase {
+    Crawl,
+    Js,
+    Probe,
+}
+
+impl Phase {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Phase::Crawl => "crawl",
+            Phase::Js => "js",
+            Phase::Probe => "probe",
+        }
+    }
+}
+
+// URL类别，优先按响应的 Content-Type 判断
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum UrlKind {
+    Html,
+    // 样式表、压缩包、媒体等其他静态资源
+    Static,
+    // JSON 接口
+    Api,
+    Js,
+    Document,
+    Image,
+    Font,
+    // 无法判断类型
+    Other,
+}
+
+impl UrlKind {
+    pub const ALL: [UrlKind; 8] = [
+        UrlKind::Html,
+        UrlKind::Static,
+        UrlKind::Api,
+        UrlKind::Js,
+        UrlKind::Document,
+        UrlKind::Image,
+        UrlKind::Font,
+        UrlKind::Other,
+    ];
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            UrlKind::Html => "html",
+            UrlKind::Static => "static",
+            UrlKind::Api => "api",
+            UrlKind::Js => "js",
+            UrlKind::Document => "document",
+            UrlKind::Image => "image",
+            UrlKind::Font => "font",
+            UrlKind::Other => "other",
+        }
+    }
+
+    // 报告中的中文名称
+    pub fn label(&self) -> &'static str {
+        match self {
+            UrlKind::Html => "HTML 页面",
+            UrlKind::Static => "静态资源",
+            UrlKind::Api => "接口地址",
+            UrlKind::Js => "JavaScript",
+            UrlKind::Document => "文档",
+            UrlKind::Image => "图片",
+            UrlKind::Font => "字体",
+            UrlKind::Other => "其他",
+        }
+    }
+
+    // 页面与接口以外的资源文件
+    pub fn is_static(&self) -> bool {
+        matches!(
+            self,
+            UrlKind::Static | UrlKind::Js | UrlKind::Document | UrlKind::Image | UrlKind::Font
+        )
+    }
+}
+
+// 发现的URL及其来源页面或JS
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DiscoveredUrl {
+    pub url: String,
+    pub kind: UrlKind,
+    pub source: String,
+    pub phase: Phase,
+    #[serde(with = "timestamp")]
+    pub timestamp: SystemTime,
+}
+
+impl DiscoveredUrl {
+    pub fn new(url: impl Into<String>, kind: UrlKind, source: impl Into<String>, phase: Phase) -> Self {
+        DiscoveredUrl {
+            url: url.into(),
+            kind,
+            source: source.into(),
+            phase,
+            timestamp: SystemTime::now(),
+        }
+    }
+}
+
+// 敏感信息
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Finding {
+    pub kind: String,
+    pub value: String,
+    pub source: String,
+    // 在来源内容中的行号
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub line: Option<usize>,
+    pub phase: Phase,
+    #[serde(with = "timestamp")]
+    pub timestamp: SystemTime,
+}
+
+impl Finding {
+    pub fn new(
+        kind: impl Into<String>,
+        value: impl Into<String>,
+        source: impl Into<String>,
+        phase: Phase,
+    ) -> Self {
+        Finding {
+            kind: kind.into(),
+            value: value.into(),
+            source: source.into(),
+            line: None,
+            phase,
+            timestamp: SystemTime::now(),
+        }
+    }
+
+    pub fn at_line(mut self, line: usize) -> Self {
+        self.line = Some(line);
+        self
+    }
+}
+
+// 表单字段
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct FormField {
+    pub name: String,
+    // input 的 type，select/textarea 记为标签名
+    #[serde(rename = "type")]
+    pub kind: String,
+}
+
+// 页面中的表单，通常就是后端接口
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Form {
+    pub action: String,
+    pub method: String,
+    pub enctype: String,
+    pub fields: Vec<FormField>,
+    // 表单所在页面
+    pub source: String,
+    #[serde(with = "timestamp")]
+    pub timestamp: SystemTime,
+}
+
+impl Form {
+    // 字段名列表，以逗号分隔
+    pub fn field_names(&self) -> String {
+        self.fields
+            .iter()
+            .map(|f| f.name.as_str())
+            .collect::<Vec<_>>()
+            .join(",")
+    }
+}
+
+// 无法解析的链接等诊断信息
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Diagnostic {
+    pub value: String,
+    pub reason: String,
+    // 所在页面
+    pub source: String,
+    #[serde(with = "timestamp")]
+    pub timestamp: SystemTime,
+}
+
+impl Diagnostic {
+    pub fn new(value: impl Into<String>, reason: impl Into<String>, source: impl Into<String>) -> Self {
+        Diagnostic {
+            value: value.into(),
+            reason: reason.into(),
+            source: source.into(),
+            timestamp: SystemTime::now(),
+        }
+    }
+}
+
+// 爬取过的URL：响应状态、跳转目标或失败原因
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CrawledPage {
+    pub url: String,
+    pub status: Option<u16>,
+    // 3xx 的 Location，或跳转后的最终地址
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub redirect: Option<String>,
+    // 不跟随跳转时逐跳记录的跳转链，status 为最终页面的状态码
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub redirects: Vec<RedirectHop>,
+    // 错误类别，如 timeout、connect、body
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub error_kind: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+    // 发现该链接的页面
+    pub source: String,
+    #[serde(with = "timestamp")]
+    pub timestamp: SystemTime,
+}
+
+impl CrawledPage {
+    pub fn new(url: impl Into<String>, source: impl Into<String>) -> Self {
+        CrawledPage {
+            url: url.into(),
+            status: None,
+            redirect: None,
+            redirects: Vec::new(),
+            error_kind: None,
+            error: None,
+            source: source.into(),
+            timestamp: SystemTime::now(),
+        }
+    }
+
+    // 请求失败或 4xx/5xx
+    pub fn is_error(&self) -> bool {
+        self.error_kind.is_some() || self.status.is_none_or(|status| status >= 400)
+    }
+}
+
+// 变体过多被折叠的URL模式，通常是日历、分页等爬虫陷阱
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CollapsedPattern {
+    pub pattern: String,
+    // 已抓取的变体数
+    pub fetched: usize,
+    // 超出上限未抓取的变体数
+    pub skipped: usize,
+    // 第一个未抓取的URL
+    pub example: String,
+    #[serde(with = "timestamp")]
+    pub timestamp: SystemTime,
+}
+
+// 页面中的内联脚本与事件处理器代码，只在扫描过程中使用
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct InlineScript {
+    // 所在页面
+    pub source: String,
+    pub content: String,
+}
+
+// 跳转链中的一跳
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RedirectHop {
+    pub url: String,
+    pub status: u16,
+    // 解析为绝对地址的 Location
+    pub location: String,
+    // 该跳响应的 Content-Length
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub content_length: Option<u64>,
+}
+
+// 单个URL的探测结果
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ProbeResult {
+    pub url: String,
+    // 不跟随跳转时为第一个响应的状态码
+    pub status: Option<u16>,
+    pub content_length: Option<u64>,
+    pub error: Option<String>,
+    // 最终跳转到的地址
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub location: Option<String>,
+    // 不跟随跳转时逐跳记录的跳转链
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub redirects: Vec<RedirectHop>,
+    #[serde(with = "timestamp")]
+    pub timestamp: SystemTime,
+}
+
+// 一个目标的完整扫描报告
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ScanReport {
+    pub base_url: String,
+    #[serde(with = "timestamp")]
+    pub started_at: SystemTime,
+    #[serde(with = "timestamp::option", default)]
+    pub finished_at: Option<SystemTime>,
+    pub base_urls: Vec<String>,
+    pub urls: Vec<DiscoveredUrl>,
+    pub probes: Vec<ProbeResult>,
+    pub domains: Vec<String>,
+    pub findings: Vec<Finding>,
+    #[serde(default)]
+    pub forms: Vec<Form>,
+    #[serde(default)]
+    pub diagnostics: Vec<Diagnostic>,
+    #[serde(default)]
+    pub collapsed: Vec<CollapsedPattern>,
+    #[serde(default)]
+    pub crawled: Vec<CrawledPage>,
+    // 请求总数上限耗尽导致结果不完整
+    #[serde(default)]
+    pub truncated: bool,
+    // 等待 JS 提取阶段处理的内联脚本，不写入报告
+    #[serde(skip)]
+    pub scripts: Vec<InlineScript>,
+}
+
+impl ScanReport {
+    pub fn new(base_url: impl Into<String>) -> Self {
+        let base_url = base_url.into();
+        ScanReport {
+            base_urls: vec![base_url.clone()],
+            base_url,
+            started_at: SystemTime::now(),
+            finished_at: None,
+            urls: Vec::new(),
+            probes: Vec::new(),
+            domains: Vec::new(),
+            findings: Vec::new(),
+            forms: Vec::new(),
+            diagnostics: Vec::new(),
+            collapsed: Vec::new(),
+            crawled: Vec::new(),
+            truncated: false,
+            scripts: Vec::new(),
+        }
+    }
+
+    // 按类别列出URL
+    pub fn urls_of(&self, kind: UrlKind) -> impl Iterator<Item = &DiscoveredUrl> {
+        self.urls.iter().filter(move |u| u.kind == kind)
+    }
+
+    pub fn count_of(&self, kind: UrlKind) -> usize {
+        self.urls_of(kind).count()
+    }
+
+    // 各类资源文件的总数
+    pub fn static_count(&self) -> usize {
+        self.urls.iter().filter(|u| u.kind.is_static()).count()
+    }
+
+    // 请求失败或返回 4xx/5xx 的爬取记录
+    pub fn crawl_errors(&self) -> impl Iterator<Item = &CrawledPage> {
+        self.crawled.iter().filter(|page| page.is_error())
+    }
+
+    // 去重后的全部URL
+    pub fn all_urls(&self) -> Vec<String> {
+        let mut all_urls: Vec<String> = self.urls.iter().map(|u| u.url.clone()).collect();
+        all_urls.sort();
+        all_urls.dedup();
+        all_urls
+    }
+
+    // 排序并去重，同一URL同一类别只保留第一次发现的记录
+    pub fn sort(&mut self) {
+        self.urls
+            .sort_by(|a, b| a.kind.cmp(&b.kind).then_with(|| a.url.cmp(&b.url)));
+        self.urls.dedup_by(|b, a| a.kind == b.kind && a.url == b.url);
+        self.domains.sort();
+        self.domains.dedup();
+        self.crawled.sort_by(|a, b| a.url.cmp(&b.url));
+        // 公共表单（如每页都有的搜索框）只保留一份
+        let mut seen = HashSet::new();
+        self.forms
+            .retain(|f| seen.insert((f.action.clone(), f.method.clone(), f.fields.clone())));
+    }
+}
+
+// 时间戳以 RFC 3339 字符串序列化
+pub mod timestamp {
+    use std::time::SystemTime;
+
+    use serde::{Deserialize, Deserializer, Serializer, de};
+
+    pub fn format(time: &SystemTime) -> String {
+        humantime::format_rfc3339_millis(*time).to_string()
+    }
+
+    pub fn serialize<S: Serializer>(time: &SystemTime, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&format(time))
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<SystemTime, D::Error> {
+        let value = String::deserialize(deserializer)?;
+        humantime::parse_rfc3339_weak(&value).map_err(de::Error::custom)
+    }
+
+    pub mod option {
+        use std::time::SystemTime;
+
+        use serde::{Deserialize, Deserializer, Serializer, de};
+
+        pub fn serialize<S: Serializer>(
+            time: &Option<SystemTime>,
+            serializer: S,
+        ) -> Result<S::Ok, S::Error> {
+            match time {
+                Some(time) => super::serialize(time, serializer),
+                None => serializer.serialize_none(),
+            }
+        }
+
+        pub fn deserialize<'de, D: Deserializer<'de>>(
+            deserializer: D,
+        ) -> Result<Option<SystemTime>, D::Error> {
+            Option::<String>::deserialize(deserializer)?
+                .map(|value| humantime::parse_rfc3339_weak(&value).map_err(de::Error::custom))
+                .transpose()
+        }
+    }
+}
@@ -0,0 +1,551 @@
+pub mod html;
+pub mod json;
+pub mod sarif;
+
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::SystemTime;
+
+use colored::*;
+use csv::{Writer, WriterBuilder};
+use url::Url;
+
+use crate::Result;
+use crate::batch::TargetOutcome;
+use crate::model::{CrawledPage, ProbeResult, ScanReport, UrlKind};
+
+// 打印爬取到的 HTML 页面与各类资源，接口地址由 print_apis 打印
+pub fn print_crawl(report: &ScanReport) {
+    for kind in UrlKind::ALL.into_iter().filter(|k| *k != UrlKind::Api) {
+        if kind != UrlKind::Html && report.count_of(kind) == 0 {
+            continue;
+        }
+        println!("{}", format!("\n=== {}类 ===", kind.label()).cyan());
+        for discovered in report.urls_of(kind) {
+            println!("{}", discovered.url.truecolor(255, 250, 205));
+        }
+    }
+    println!(
+        "{} {}",
+        "[*]static_urls大小:".yellow(),
+        report.static_count().to_string().yellow()
+    );
+}
+
+// 打印页面中发现的表单
+pub fn print_forms(report: &ScanReport) {
+    println!("{}", "\n=== 表单 ===".cyan());
+    if report.forms.is_empty() {
+        println!("{}", "[*]未发现表单".truecolor(255, 215, 0));
+        return;
+    }
+    for form in &report.forms {
+        println!(
+            "{} {} [{}] {} {}",
+            form.method.truecolor(255, 250, 205),
+            form.action.truecolor(255, 250, 205),
+            form.field_names(),
+            form.enctype,
+            format!("(来源: {})", form.source).truecolor(128, 128, 128)
+        );
+    }
+}
+
+// 打印从JS中提取的接口地址
+pub fn print_apis(report: &ScanReport) {
+    println!(
+        "{}",
+        "\n=== 从JS文件中提取的接口地址 ===".truecolor(87, 182, 194)
+    );
+    for discovered in report.urls_of(UrlKind::Api) {
+        println!("{}", discovered.url);
+    }
+}
+
+// 按状态码为探测结果着色
+fn colorize_status(status: u16, text: &str) -> ColoredString {
+    match status {
+        200 => text.green(),
+        302 => text.purple(),
+        401 | 403 => text.yellow(),
+        404 => text.blue(),
+        500..=599 => text.red(),
+        _ => text.normal(),
+    }
+}
+
+fn format_length(content_length: Option<u64>) -> String {
+    content_length.map_or("N/A".to_string(), |len| len.to_string())
+}
+
+// 单条探测结果的终端展示
+pub fn format_probe(probe: &ProbeResult) -> String {
+    match probe.status {
+        Some(status) if probe.error.is_none() => {
+            let mut line = format!(
+                "Code: {} Length: {} URL: {}",
+                status,
+                format_length(probe.content_length),
+                probe.url
+            );
+            // 跳转链逐跳列出，自动跟随时只有最终地址
+            for hop in probe.redirects.iter().skip(1) {
+                line.push_str(&format!(" -> [{}] {}", hop.status, hop.url));
+            }
+            if let Some(location) = &probe.location {
+                line.push_str(&format!(" -> {}", location));
+            }
+            colorize_status(status, &line).to_string()
+        }
+        _ => format!(
+            "{} {} - {}",
+            "[*]访问失败: ".red(),
+            probe.url.red(),
+            probe.error.as_deref().unwrap_or("未知错误").red()
+        ),
+    }
+}
+
+// 打印探测结果、域名、统计信息与敏感信息
+pub fn print_summary(report: &ScanReport) {
+    println!("{}", "\n=== 所有URL访问结果 ===".truecolor(87, 182, 194));
+    for probe in &report.probes {
+        println!("{}", format_probe(probe));
+    }
+
+    // 打印去重后的域名列表
+    println!(
+        "{}",
+        "\n=== 从JS和HTML中提取的去重域名 ===".truecolor(87, 182, 194)
+    );
+    if report.domains.is_empty() {
+        println!("{}", "[*]未发现域名".truecolor(255, 215, 0));
+    } else {
+        for domain in &report.domains {
+            println!("{}", domain.truecolor(255, 250, 205));
+        }
+    }
+
+    println!("\n=== 统计信息 ===");
+    println!("[]提取到的URL总数: {}", report.all_urls().len());
+    if report.truncated {
+        println!("{}", "[!]请求总数已达上限，结果不完整".yellow());
+    }
+    println!("[!]操作完成，临时文件夹已删除。");
+
+    // 统一打印敏感信息表格
+    println!("{}", "\n=== 检测到的敏感信息 ===".truecolor(255, 215, 0));
+    if report.findings.is_empty() {
+        println!("{}", "[*]未发现敏感信息".truecolor(255, 215, 0));
+    } else {
+        println!(
+            "{:<5} | {:<10} | {}",
+            "序号".truecolor(255, 215, 0),
+            "类型".truecolor(255, 215, 0),
+            "值".truecolor(255, 215, 0)
+        );
+        println!(
+            "{}",
+            "---------------------------------------------".truecolor(255, 215, 0)
+        );
+        for (i, finding) in report.findings.iter().enumerate() {
+            println!(
+                "{:<5} | {:<10} | {}",
+                (i + 1).to_string().truecolor(255, 215, 0),
+                finding.kind.truecolor(255, 215, 0),
+                finding.value.truecolor(255, 215, 0)
+            );
+        }
+    }
+}
+
+// 打印无法解析的链接
+pub fn print_diagnostics(report: &ScanReport) {
+    if report.diagnostics.is_empty() {
+        return;
+    }
+    println!("{}", "\n=== 无法解析的链接 ===".truecolor(87, 182, 194));
+    for diagnostic in &report.diagnostics {
+        println!(
+            "{} - {} {}",
+            diagnostic.value.red(),
+            diagnostic.reason,
+            format!("(来源: {})", diagnostic.source).truecolor(128, 128, 128)
+        );
+    }
+}
+
+// 爬取错误的状态或错误类别
+fn crawl_error_status(page: &CrawledPage) -> String {
+    match (&page.error_kind, page.status) {
+        (Some(kind), _) => kind.clone(),
+        (None, Some(status)) => status.to_string(),
+        (None, None) => "N/A".to_string(),
+    }
+}
+
+// 打印请求失败或返回 4xx/5xx 的爬取记录
+pub fn print_crawl_errors(report: &ScanReport) {
+    if report.crawl_errors().next().is_none() {
+        return;
+    }
+    println!("{}", "\n=== 爬取错误 ===".truecolor(87, 182, 194));
+    for page in report.crawl_errors() {
+        println!(
+            "{} {} {}",
+            format!("[{}]", crawl_error_status(page)).red(),
+            page.url,
+            format!("(来源: {})", page.source).truecolor(128, 128, 128)
+        );
+    }
+}
+
+// 打印变体过多被折叠的URL模式
+pub fn print_collapsed(report: &ScanReport) {
+    if report.collapsed.is_empty() {
+        return;
+    }
+    println!("{}", "\n=== 疑似爬虫陷阱（已折叠的URL模式） ===".truecolor(87, 182, 194));
+    for collapsed in &report.collapsed {
+        println!(
+            "{} - 已抓取 {} 个，跳过 {} 个 {}",
+            collapsed.pattern.yellow(),
+            collapsed.fetched,
+            collapsed.skipped,
+            format!("(例如: {})", collapsed.example).truecolor(128, 128, 128)
+        );
+    }
+}
+
+// 打印完整报告
+pub fn print_report(report: &ScanReport) {
+    print_crawl(report);
+    print_forms(report);
+    print_apis(report);
+    print_summary(report);
+    print_diagnostics(report);
+    print_collapsed(report);
+    print_crawl_errors(report);
+}
+
+// 输出文件名：协议、主机名（含非默认端口）与路径，例如 https-example-com-app
+pub fn report_stem(report: &ScanReport) -> Result<String> {
+    let url_obj = Url::parse(&report.base_url)?;
+    let host = url_obj.host_str().unwrap_or("unknown").replace('.', "-");
+    let mut stem = format!("{}-{}", url_obj.scheme(), host);
+    // 同一主机不同端口、不同路径的目标分别输出
+    if let Some(port) = url_obj.port() {
+        stem = format!("{}-{}", stem, port);
+    }
+    let path: String = url_obj
+        .path()
+        .split(|c: char| !c.is_ascii_alphanumeric())
+        .filter(|part| !part.is_empty())
+        .collect::<Vec<_>>()
+        .join("-")
+        .chars()
+        .take(60)
+        .collect();
+    if !path.is_empty() {
+        stem = format!("{}-{}", stem, path.trim_end_matches('-'));
+    }
+    Ok(stem)
+}
+
+// 结果文件格式
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OutputFormat {
+    Csv,
+    Json,
+    Jsonl,
+    Html,
+    Sarif,
+}
+
+impl OutputFormat {
+    pub fn extension(&self) -> &'static str {
+        match self {
+            OutputFormat::Csv => "csv",
+            OutputFormat::Json => "json",
+            OutputFormat::Jsonl => "jsonl",
+            OutputFormat::Html => "html",
+            OutputFormat::Sarif => "sarif",
+        }
+    }
+}
+
+// 按格式写出单个目标的结果文件，返回文件路径
+pub fn write_report(report: &ScanReport, output_dir: &Path, format: OutputFormat) -> Result<PathBuf> {
+    if format == OutputFormat::Csv {
+        return write_csv(report, output_dir);
+    }
+    fs::create_dir_all(output_dir)?;
+    let path = output_dir.join(format!("{}.{}", report_stem(report)?, format.extension()));
+    match format {
+        OutputFormat::Json => json::write_json(report, &path)?,
+        OutputFormat::Jsonl => json::write_jsonl(report, &path)?,
+        OutputFormat::Html => html::write_html(report, &path)?,
+        OutputFormat::Sarif => sarif::write_sarif(report, &path)?,
+        OutputFormat::Csv => unreachable!(),
+    }
+    Ok(path)
+}
+
+// 写出 CSV：探测结果表 + 敏感信息表，以及非空的表单表与爬取错误表，返回文件路径
+pub fn write_csv(report: &ScanReport, output_dir: &Path) -> Result<PathBuf> {
+    fs::create_dir_all(output_dir)?;
+    let csv_path = output_dir.join(format!("{}.csv", report_stem(report)?));
+    // 表单表比前两张表多一列
+    let mut writer = WriterBuilder::new().flexible(true).from_path(&csv_path)?;
+
+    writer.write_record(["Code", "Length", "URL", "Error", "Location"])?;
+    for probe in &report.probes {
+        writer.write_record([
+            probe.status.map_or("N/A".to_string(), |s| s.to_string()),
+            format_length(probe.content_length),
+            probe.url.clone(),
+            probe.error.clone().unwrap_or_default(),
+            probe.location.clone().unwrap_or_default(),
+        ])?;
+    }
+    writer.write_record(["", "", "", ""])?;
+    writer.write_record(["序号", "类型", "值", "来源"])?;
+    for (i, finding) in report.findings.iter().enumerate() {
+        writer.write_record([
+            (i + 1).to_string(),
+            finding.kind.clone(),
+            finding.value.clone(),
+            finding.source.clone(),
+        ])?;
+    }
+    if !report.forms.is_empty() {
+        writer.write_record(["", "", "", ""])?;
+        writer.write_record(["方法", "表单地址", "编码", "字段", "来源"])?;
+        for form in &report.forms {
+            let fields: Vec<String> = form
+                .fields
+                .iter()
+                .map(|f| format!("{}:{}", f.name, f.kind))
+                .collect();
+            writer.write_record([
+                form.method.as_str(),
+                form.action.as_str(),
+                form.enctype.as_str(),
+                &fields.join(","),
+                form.source.as_str(),
+            ])?;
+        }
+    }
+    if report.crawl_errors().next().is_some() {
+        writer.write_record(["", "", "", ""])?;
+        writer.write_record(["状态", "爬取地址", "跳转", "错误", "来源"])?;
+        for page in report.crawl_errors() {
+            writer.write_record([
+                crawl_error_status(page).as_str(),
+                page.url.as_str(),
+                page.redirect.as_deref().unwrap_or_default(),
+                page.error.as_deref().unwrap_or_default(),
+                page.source.as_str(),
+            ])?;
+        }
+    }
+    writer.flush()?;
+    Ok(csv_path)
+}
+
+// CSV 中的表格，按表头行区分
+#[derive(PartialEq)]
+enum CsvSection {
+    Probes,
+    Findings,
+    Forms,
+    CrawlErrors,
+}
+
+// 重新展示 write_csv 输出的CSV文件
+pub fn print_csv_report(csv_path: &Path) -> Result<()> {
+    let mut reader = csv::ReaderBuilder::new()
+        .has_headers(false)
+        .flexible(true)
+        .from_path(csv_path)?;
+
+    let mut section = CsvSection::Probes;
+    let mut sensitive_count = 0;
+    for record in reader.records() {
+        let record = record?;
+        let fields: Vec<&str> = record.iter().collect();
+        let header = fields.first().copied();
+        // 离开敏感信息表时，空表给出提示
+        if matches!(header, Some("Code" | "序号" | "方法" | "状态"))
+            && section == CsvSection::Findings
+            && sensitive_count == 0
+        {
+            println!("{}", "[*]未发现敏感信息".truecolor(255, 215, 0));
+        }
+        match header {
+            Some("Code") => {
+                section = CsvSection::Probes;
+                println!("{}", "\n=== 所有URL访问结果 ===".truecolor(87, 182, 194));
+                continue;
+            }
+            Some("序号") => {
+                section = CsvSection::Findings;
+                println!("{}", "\n=== 检测到的敏感信息 ===".truecolor(255, 215, 0));
+                continue;
+            }
+            Some("方法") => {
+                section = CsvSection::Forms;
+                println!("{}", "\n=== 表单 ===".cyan());
+                continue;
+            }
+            Some("状态") => {
+                section = CsvSection::CrawlErrors;
+                println!("{}", "\n=== 爬取错误 ===".truecolor(87, 182, 194));
+                continue;
+            }
+            _ => {}
+        }
+        if fields.len() < 3 || fields.iter().all(|f| f.is_empty()) {
+            continue;
+        }
+        match section {
+            CsvSection::Probes => {
+                let probe = ProbeResult {
+                    url: fields[2].to_string(),
+                    status: fields[0].parse().ok(),
+                    content_length: fields[1].parse().ok(),
+                    error: fields
+                        .get(3)
+                        .filter(|e| !e.is_empty())
+                        .map(|e| e.to_string()),
+                    location: fields
+                        .get(4)
+                        .filter(|l| !l.is_empty())
+                        .map(|l| l.to_string()),
+                    redirects: Vec::new(),
+                    timestamp: SystemTime::now(),
+                };
+                println!("{}", format_probe(&probe));
+            }
+            CsvSection::Findings => {
+                sensitive_count += 1;
+                println!(
+                    "{:<5} | {:<10} | {}",
+                    fields[0].truecolor(255, 215, 0),
+                    fields[1].truecolor(255, 215, 0),
+                    fields[2].truecolor(255, 215, 0)
+                );
+            }
+            CsvSection::Forms => println!(
+                "{} {} [{}] {}",
+                fields[0].truecolor(255, 250, 205),
+                fields[1].truecolor(255, 250, 205),
+                fields.get(3).unwrap_or(&""),
+                fields[2]
+            ),
+            CsvSection::CrawlErrors => println!(
+                "{} {} {}",
+                format!("[{}]", fields[0]).red(),
+                fields[1],
+                format!("(来源: {})", fields.get(4).unwrap_or(&"")).truecolor(128, 128, 128)
+            ),
+        }
+    }
+    if section == CsvSection::Findings && sensitive_count == 0 {
+        println!("{}", "[*]未发现敏感信息".truecolor(255, 215, 0));
+    }
+    Ok(())
+}
+
+// 打印批量扫描汇总
+pub fn print_batch_summary(outcomes: &[TargetOutcome]) {
+    println!("{}", "\n=== 批量扫描汇总 ===".truecolor(87, 182, 194));
+    println!(
+        "{:<40} | {:<6} | {:<6} | {:<6} | {:<6} | {}",
+        "目标".cyan(),
+        "HTML".cyan(),
+        "静态".cyan(),
+        "接口".cyan(),
+        "敏感".cyan(),
+        "状态".cyan()
+    );
+    println!("{}", "-".repeat(90));
+    for outcome in outcomes {
+        match &outcome.result {
+            Ok(report) => println!(
+                "{:<40} | {:<6} | {:<6} | {:<6} | {:<6} | {}",
+                outcome.target,
+                report.count_of(UrlKind::Html),
+                report.static_count(),
+                report.count_of(UrlKind::Api),
+                report.findings.len(),
+                if report.truncated {
+                    "不完整（请求总数已达上限）".yellow()
+                } else {
+                    "成功".green()
+                }
+            ),
+            Err(e) => println!(
+                "{:<40} | -      | -      | -      | -      | {} {}",
+                outcome.target,
+                "失败".red(),
+                e.red()
+            ),
+        }
+    }
+    let failed = outcomes.iter().filter(|o| o.result.is_err()).count();
+    let truncated = outcomes
+        .iter()
+        .filter(|o| o.result.as_ref().is_ok_and(|report| report.truncated))
+        .count();
+    println!(
+        "[]目标总数: {}  成功: {}  不完整: {}  失败: {}",
+        outcomes.len(),
+        outcomes.len() - failed - truncated,
+        truncated,
+        failed
+    );
+}
+
+// 写出批量扫描汇总 CSV，返回文件路径
+pub fn write_summary_csv(outcomes: &[TargetOutcome], output_dir: &Path) -> Result<PathBuf> {
+    fs::create_dir_all(output_dir)?;
+    let csv_path = output_dir.join("summary.csv");
+    let mut writer = Writer::from_path(&csv_path)?;
+
+    writer.write_record([
+        "Target", "Status", "HTML", "Static", "API", "URLs", "Domains", "Sensitive", "Error",
+    ])?;
+    for outcome in outcomes {
+        match &outcome.result {
+            Ok(report) => writer.write_record([
+                outcome.target.clone(),
+                if report.truncated { "truncated" } else { "ok" }.to_string(),
+                report.count_of(UrlKind::Html).to_string(),
+                report.static_count().to_string(),
+                report.count_of(UrlKind::Api).to_string(),
+                report.all_urls().len().to_string(),
+                report.domains.len().to_string(),
+                report.findings.len().to_string(),
+                if report.truncated {
+                    "请求总数已达上限".to_string()
+                } else {
+                    String::new()
+                },
+            ])?,
+            Err(e) => writer.write_record([
+                outcome.target.as_str(),
+                "failed",
+                "",
+                "",
+                "",
+                "",
+                "",
+                "",
+                e.as_str(),
+            ])?,
+        }
+    }
+    writer.flush()?;
+    Ok(csv_path)
+}
@@ -0,0 +1,325 @@
+use std::fs;
+use std::path::Path;
+
+use crate::Result;
+use crate::model::{ScanReport, UrlKind, timestamp};
+use crate::output::crawl_error_status;
+
+const STYLE: &str = r#"
+body { font-family: -apple-system, "Segoe UI", "PingFang SC", "Microsoft YaHei", sans-serif; margin: 24px; color: #222; background: #fafafa; }
+h1 { font-size: 22px; margin-bottom: 4px; }
+h2 { font-size: 17px; margin: 28px 0 8px; }
+.meta { color: #666; font-size: 13px; }
+.cards { display: flex; flex-wrap: wrap; gap: 12px; margin-top: 16px; }
+.card { background: #fff; border: 1px solid #e3e3e3; border-radius: 6px; padding: 10px 16px; min-width: 110px; }
+.card b { display: block; font-size: 22px; }
+.card span { color: #666; font-size: 12px; }
+input.filter { width: 320px; padding: 5px 8px; margin-bottom: 6px; border: 1px solid #ccc; border-radius: 4px; }
+table { border-collapse: collapse; width: 100%; background: #fff; font-size: 13px; }
+th, td { border: 1px solid #e3e3e3; padding: 5px 8px; text-align: left; vertical-align: top; word-break: break-all; }
+th { background: #f0f3f6; cursor: pointer; user-select: none; white-space: nowrap; }
+th.asc::after { content: " ▲"; }
+th.desc::after { content: " ▼"; }
+tr.hidden { display: none; }
+.s2 { color: #1a7f37; } .s3 { color: #8250df; } .s4 { color: #bf8700; } .s404 { color: #0969da; } .s5, .err { color: #cf222e; }
+.empty { color: #888; font-style: italic; }
+"#;
+
+const SCRIPT: &str = r#"
+document.querySelectorAll("table.data").forEach(function (table) {
+  var tbody = table.tBodies[0];
+  table.querySelectorAll("th").forEach(function (th, index) {
+    th.addEventListener("click", function () {
+      var asc = !th.classList.contains("asc");
+      table.querySelectorAll("th").forEach(function (h) { h.classList.remove("asc", "desc"); });
+      th.classList.add(asc ? "asc" : "desc");
+      var rows = Array.prototype.slice.call(tbody.rows);
+      rows.sort(function (a, b) {
+        var x = a.cells[index].textContent, y = b.cells[index].textContent;
+        var nx = parseFloat(x), ny = parseFloat(y);
+        var cmp = (!isNaN(nx) && !isNaN(ny)) ? nx - ny : x.localeCompare(y);
+        return asc ? cmp : -cmp;
+      });
+      rows.forEach(function (row) { tbody.appendChild(row); });
+    });
+  });
+});
+document.querySelectorAll("input.filter").forEach(function (input) {
+  input.addEventListener("input", function () {
+    var keyword = input.value.toLowerCase();
+    var table = document.getElementById(input.dataset.table);
+    Array.prototype.forEach.call(table.tBodies[0].rows, function (row) {
+      row.classList.toggle("hidden", row.textContent.toLowerCase().indexOf(keyword) < 0);
+    });
+  });
+});
+"#;
+
+fn escape(value: &str) -> String {
+    let mut escaped = String::with_capacity(value.len());
+    for c in value.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&#39;"),
+            _ => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+// 单元格：已转义的内容与可选的 class
+struct Cell {
+    html: String,
+    class: &'static str,
+}
+
+impl Cell {
+    fn text(value: &str) -> Self {
+        Cell {
+            html: escape(value),
+            class: "",
+        }
+    }
+
+    fn styled(value: &str, class: &'static str) -> Self {
+        Cell {
+            html: escape(value),
+            class,
+        }
+    }
+}
+
+// 可排序、可筛选的表格
+fn section(html: &mut String, id: &str, title: &str, headers: &[&str], rows: Vec<Vec<Cell>>) {
+    html.push_str(&format!("<h2>{} ({})</h2>\n", escape(title), rows.len()));
+    if rows.is_empty() {
+        html.push_str("<p class=\"empty\">无</p>\n");
+        return;
+    }
+    html.push_str(&format!(
+        "<input class=\"filter\" data-table=\"{}\" placeholder=\"筛选...\">\n",
+        id
+    ));
+    html.push_str(&format!("<table class=\"data\" id=\"{}\">\n<thead><tr>", id));
+    for header in headers {
+        html.push_str(&format!("<th>{}</th>", escape(header)));
+    }
+    html.push_str("</tr></thead>\n<tbody>\n");
+    for row in rows {
+        html.push_str("<tr>");
+        for cell in row {
+            if cell.class.is_empty() {
+                html.push_str(&format!("<td>{}</td>", cell.html));
+            } else {
+                html.push_str(&format!("<td class=\"{}\">{}</td>", cell.class, cell.html));
+            }
+        }
+        html.push_str("</tr>\n");
+    }
+    html.push_str("</tbody>\n</table>\n");
+}
+
+fn status_class(status: u16) -> &'static str {
+    match status {
+        200..=299 => "s2",
+        300..=399 => "s3",
+        404 => "s404",
+        400..=499 => "s4",
+        _ => "s5",
+    }
+}
+
+fn url_rows(report: &ScanReport, kind: UrlKind) -> Vec<Vec<Cell>> {
+    report
+        .urls_of(kind)
+        .map(|u| {
+            vec![
+                Cell::text(&u.url),
+                Cell::text(&u.source),
+                Cell::text(&timestamp::format(&u.timestamp)),
+            ]
+        })
+        .collect()
+}
+
+// 生成单文件 HTML 报告
+pub fn render(report: &ScanReport) -> String {
+    let mut html = String::new();
+    html.push_str("<!DOCTYPE html>\n<html lang=\"zh-CN\">\n<head>\n<meta charset=\"utf-8\">\n");
+    html.push_str(&format!(
+        "<title>扫描报告 - {}</title>\n<style>{}</style>\n</head>\n<body>\n",
+        escape(&report.base_url),
+        STYLE
+    ));
+    html.push_str(&format!("<h1>扫描报告：{}</h1>\n", escape(&report.base_url)));
+    html.push_str(&format!(
+        "<div class=\"meta\">开始时间 {} · 结束时间 {}</div>\n",
+        timestamp::format(&report.started_at),
+        report
+            .finished_at
+            .map_or("-".to_string(), |t| timestamp::format(&t))
+    ));
+
+    // 统计信息
+    let cards = [
+        ("URL总数", report.all_urls().len()),
+        ("表单", report.forms.len()),
+        ("探测URL", report.probes.len()),
+        ("域名", report.domains.len()),
+        ("敏感信息", report.findings.len()),
+    ];
+    let kind_cards = UrlKind::ALL
+        .iter()
+        .map(|kind| (kind.label(), report.count_of(*kind)))
+        .filter(|(_, count)| *count > 0);
+    html.push_str("<div class=\"cards\">\n");
+    for (label, count) in cards.into_iter().take(1).chain(kind_cards).chain(cards.into_iter().skip(1)) {
+        html.push_str(&format!(
+            "<div class=\"card\"><b>{}</b><span>{}</span></div>\n",
+            count, label
+        ));
+    }
+    html.push_str("</div>\n");
+
+    let url_headers = ["URL", "来源", "时间"];
+    for kind in UrlKind::ALL {
+        // 空的次要类别不单独成节
+        if report.count_of(kind) == 0 && !matches!(kind, UrlKind::Html | UrlKind::Static | UrlKind::Api) {
+            continue;
+        }
+        let id = format!("{}-urls", kind.as_str());
+        section(&mut html, &id, kind.label(), &url_headers, url_rows(report, kind));
+    }
+
+    let form_rows = report
+        .forms
+        .iter()
+        .map(|f| {
+            let fields: Vec<String> = f
+                .fields
+                .iter()
+                .map(|field| format!("{}:{}", field.name, field.kind))
+                .collect();
+            vec![
+                Cell::text(&f.method),
+                Cell::text(&f.action),
+                Cell::text(&f.enctype),
+                Cell::text(&fields.join(", ")),
+                Cell::text(&f.source),
+            ]
+        })
+        .collect();
+    section(&mut html, "forms", "表单", &["方法", "地址", "编码", "字段", "来源"], form_rows);
+
+    let probe_rows = report
+        .probes
+        .iter()
+        .map(|p| {
+            let status = match (p.status, &p.error) {
+                (Some(status), None) => Cell::styled(&status.to_string(), status_class(status)),
+                _ => Cell::styled("N/A", "err"),
+            };
+            // 中间跳转逐跳列出，最后是最终地址
+            let location: Vec<String> = p
+                .redirects
+                .iter()
+                .skip(1)
+                .map(|hop| format!("[{}] {}", hop.status, hop.url))
+                .chain(p.location.clone())
+                .collect();
+            vec![
+                status,
+                Cell::text(&p.content_length.map_or("N/A".to_string(), |l| l.to_string())),
+                Cell::text(&p.url),
+                Cell::text(&location.join(" -> ")),
+                Cell::styled(p.error.as_deref().unwrap_or(""), "err"),
+            ]
+        })
+        .collect();
+    section(&mut html, "probes", "探测结果", &["状态码", "长度", "URL", "跳转", "错误"], probe_rows);
+
+    let domain_rows = report
+        .domains
+        .iter()
+        .map(|d| vec![Cell::text(d)])
+        .collect();
+    section(&mut html, "domains", "域名", &["域名"], domain_rows);
+
+    let finding_rows = report
+        .findings
+        .iter()
+        .map(|f| {
+            vec![
+                Cell::text(&f.kind),
+                Cell::text(&f.value),
+                Cell::text(&f.source),
+                Cell::text(f.phase.as_str()),
+            ]
+        })
+        .collect();
+    section(&mut html, "findings", "敏感信息", &["类型", "值", "来源", "阶段"], finding_rows);
+
+    if !report.diagnostics.is_empty() {
+        let diagnostic_rows = report
+            .diagnostics
+            .iter()
+            .map(|d| vec![Cell::styled(&d.value, "err"), Cell::text(&d.reason), Cell::text(&d.source)])
+            .collect();
+        section(&mut html, "diagnostics", "无法解析的链接", &["值", "原因", "来源"], diagnostic_rows);
+    }
+
+    if !report.collapsed.is_empty() {
+        let collapsed_rows = report
+            .collapsed
+            .iter()
+            .map(|c| {
+                vec![
+                    Cell::styled(&c.pattern, "err"),
+                    Cell::text(&c.fetched.to_string()),
+                    Cell::text(&c.skipped.to_string()),
+                    Cell::text(&c.example),
+                ]
+            })
+            .collect();
+        section(
+            &mut html,
+            "collapsed",
+            "疑似爬虫陷阱",
+            &["URL模式", "已抓取", "已跳过", "示例"],
+            collapsed_rows,
+        );
+    }
+
+    if report.crawl_errors().next().is_some() {
+        let error_rows = report
+            .crawl_errors()
+            .map(|page| {
+                vec![
+                    Cell::styled(&crawl_error_status(page), "err"),
+                    Cell::text(&page.url),
+                    Cell::text(page.redirect.as_deref().unwrap_or_default()),
+                    Cell::text(page.error.as_deref().unwrap_or_default()),
+                    Cell::text(&page.source),
+                ]
+            })
+            .collect();
+        section(
+            &mut html,
+            "crawl-errors",
+            "爬取错误",
+            &["状态", "URL", "跳转", "错误", "来源"],
+            error_rows,
+        );
+    }
+
+    html.push_str(&format!("<script>{}</script>\n</body>\n</html>\n", SCRIPT));
+    html
+}
+
+pub fn write_html(report: &ScanReport, path: &Path) -> Result<()> {
+    fs::write(path, render(report))?;
+    Ok(())
+}
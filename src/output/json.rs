@@ -0,0 +1,121 @@
+use std::fs::{self, File};
+use std::io::{BufWriter, Write};
+use std::path::Path;
+use std::time::SystemTime;
+
+use serde::Serialize;
+
+use crate::Result;
+use crate::model::{CollapsedPattern, CrawledPage, Diagnostic, DiscoveredUrl, Finding, Form, Phase, ProbeResult, ScanReport, timestamp};
+
+// JSON Lines 中的一行事件
+#[derive(Serialize)]
+#[serde(tag = "event", rename_all = "snake_case")]
+pub enum Event<'a> {
+    Url {
+        target: &'a str,
+        #[serde(flatten)]
+        url: &'a DiscoveredUrl,
+    },
+    Probe {
+        target: &'a str,
+        phase: Phase,
+        #[serde(flatten)]
+        probe: &'a ProbeResult,
+    },
+    Finding {
+        target: &'a str,
+        #[serde(flatten)]
+        finding: &'a Finding,
+    },
+    Form {
+        target: &'a str,
+        #[serde(flatten)]
+        form: &'a Form,
+    },
+    Diagnostic {
+        target: &'a str,
+        #[serde(flatten)]
+        diagnostic: &'a Diagnostic,
+    },
+    CrawlError {
+        target: &'a str,
+        #[serde(flatten)]
+        page: &'a CrawledPage,
+    },
+    Collapsed {
+        target: &'a str,
+        #[serde(flatten)]
+        collapsed: &'a CollapsedPattern,
+    },
+    Domain {
+        target: &'a str,
+        domain: &'a str,
+        #[serde(with = "timestamp")]
+        timestamp: SystemTime,
+    },
+}
+
+// 按 URL -> 探测 -> 敏感信息 -> 表单 -> 诊断 -> 爬取错误 -> 折叠模式 -> 域名 的顺序列出报告中的全部事件
+pub fn events(report: &ScanReport) -> Vec<Event<'_>> {
+    let target = report.base_url.as_str();
+    let finished_at = report.finished_at.unwrap_or(report.started_at);
+    let mut events = Vec::new();
+    events.extend(report.urls.iter().map(|url| Event::Url { target, url }));
+    events.extend(report.probes.iter().map(|probe| Event::Probe {
+        target,
+        phase: Phase::Probe,
+        probe,
+    }));
+    events.extend(
+        report
+            .findings
+            .iter()
+            .map(|finding| Event::Finding { target, finding }),
+    );
+    events.extend(report.forms.iter().map(|form| Event::Form { target, form }));
+    events.extend(
+        report
+            .diagnostics
+            .iter()
+            .map(|diagnostic| Event::Diagnostic { target, diagnostic }),
+    );
+    events.extend(report.crawl_errors().map(|page| Event::CrawlError { target, page }));
+    events.extend(
+        report
+            .collapsed
+            .iter()
+            .map(|collapsed| Event::Collapsed { target, collapsed }),
+    );
+    events.extend(report.domains.iter().map(|domain| Event::Domain {
+        target,
+        domain,
+        timestamp: finished_at,
+    }));
+    events
+}
+
+// 整个报告写成一个 JSON 文档
+pub fn write_json(report: &ScanReport, path: &Path) -> Result<()> {
+    let mut writer = BufWriter::new(File::create(path)?);
+    serde_json::to_writer_pretty(&mut writer, report)?;
+    writer.write_all(b"\n")?;
+    writer.flush()?;
+    Ok(())
+}
+
+// 每个事件写成一行 JSON
+pub fn write_jsonl(report: &ScanReport, path: &Path) -> Result<()> {
+    let mut writer = BufWriter::new(File::create(path)?);
+    for event in events(report) {
+        serde_json::to_writer(&mut writer, &event)?;
+        writer.write_all(b"\n")?;
+    }
+    writer.flush()?;
+    Ok(())
+}
+
+// 读取 write_json 输出的报告
+pub fn read_json(path: &Path) -> Result<ScanReport> {
+    Ok(serde_json::from_str(&fs::read_to_string(path)?)?)
+}
@@ -0,0 +1,133 @@
+use std::fs;
+use std::path::Path;
+
+use serde_json::{Value, json};
+
+use crate::Result;
+use crate::model::{Finding, ScanReport, timestamp};
+
+const SCHEMA: &str = "https://json.schemastore.org/sarif-2.1.0.json";
+
+// 敏感信息类型对应的 SARIF 规则：类型, 规则ID, 级别
+const RULES: &[(&str, &str, &str)] = &[
+    ("AK/SK", "access-key", "error"),
+    ("Token", "token", "error"),
+    ("APIKey", "api-key", "error"),
+    ("密码", "password", "error"),
+    ("JDBC连接", "jdbc-connection", "error"),
+    ("配置文件", "config-file", "warning"),
+    ("备份文件", "backup-file", "warning"),
+    ("WebSocket接口", "websocket-endpoint", "note"),
+    ("邮箱", "email", "note"),
+    ("手机号", "phone-number", "note"),
+    ("robots禁止路径", "robots-disallow", "note"),
+    ("登录跳转", "login-redirect", "note"),
+    ("Source Map泄露", "source-map-exposed", "warning"),
+];
+
+// 未登记的类型统一归入该规则
+const FALLBACK_RULE: (&str, &str) = ("sensitive-info", "warning");
+
+fn rule_of(kind: &str) -> (&'static str, &'static str) {
+    RULES
+        .iter()
+        .find(|(k, _, _)| *k == kind)
+        .map_or(FALLBACK_RULE, |(_, id, level)| (*id, *level))
+}
+
+fn result(finding: &Finding, rule_index: usize) -> Value {
+    let (rule_id, level) = rule_of(&finding.kind);
+    // region 只在有行号时给出；匹配值经过格式化，不是原文，放在 message 与 properties 中
+    let mut location = json!({
+        "physicalLocation": {
+            "artifactLocation": { "uri": finding.source },
+        }
+    });
+    if let Some(line) = finding.line {
+        location["physicalLocation"]["region"] = json!({ "startLine": line });
+    }
+    json!({
+        "ruleId": rule_id,
+        "ruleIndex": rule_index,
+        "level": level,
+        "message": { "text": format!("{}: {}", finding.kind, finding.value) },
+        "locations": [location],
+        "properties": { "phase": finding.phase.as_str(), "value": finding.value },
+    })
+}
+
+// 生成 SARIF 2.1.0 文档：敏感信息为结果，爬取错误为执行通知
+pub fn render(report: &ScanReport) -> Value {
+    // 只登记本次出现过的规则，按首次出现顺序
+    let mut rule_ids: Vec<&str> = Vec::new();
+    let mut rules = Vec::new();
+    let mut results = Vec::new();
+    for finding in &report.findings {
+        let (rule_id, level) = rule_of(&finding.kind);
+        let rule_index = match rule_ids.iter().position(|id| *id == rule_id) {
+            Some(index) => index,
+            None => {
+                let name = if rule_id == FALLBACK_RULE.0 { "敏感信息" } else { finding.kind.as_str() };
+                rule_ids.push(rule_id);
+                rules.push(json!({
+                    "id": rule_id,
+                    "name": rule_id,
+                    "shortDescription": { "text": format!("发现{}", name) },
+                    "defaultConfiguration": { "level": level },
+                }));
+                rule_ids.len() - 1
+            }
+        };
+        results.push(result(finding, rule_index));
+    }
+
+    // 爬取错误作为工具执行通知
+    let notifications: Vec<Value> = report
+        .crawl_errors()
+        .map(|page| {
+            let reason = match (&page.error, page.status) {
+                (Some(error), _) => error.clone(),
+                (None, Some(status)) => format!("HTTP {}", status),
+                (None, None) => "N/A".to_string(),
+            };
+            json!({
+                "level": "warning",
+                "message": { "text": format!("爬取失败 {}: {}", page.url, reason) },
+                "locations": [{
+                    "physicalLocation": { "artifactLocation": { "uri": page.url } },
+                }],
+                "timeUtc": timestamp::format(&page.timestamp),
+            })
+        })
+        .collect();
+    let mut invocation = json!({
+        "executionSuccessful": true,
+        "startTimeUtc": timestamp::format(&report.started_at),
+        "toolExecutionNotifications": notifications,
+    });
+    if let Some(finished_at) = &report.finished_at {
+        invocation["endTimeUtc"] = json!(timestamp::format(finished_at));
+    }
+
+    json!({
+        "$schema": SCHEMA,
+        "version": "2.1.0",
+        "runs": [{
+            "tool": {
+                "driver": {
+                    "name": env!("CARGO_PKG_NAME"),
+                    "version": env!("CARGO_PKG_VERSION"),
+                    "rules": rules,
+                }
+            },
+            "invocations": [invocation],
+            "properties": { "target": report.base_url },
+            "results": results,
+        }]
+    })
+}
+
+pub fn write_sarif(report: &ScanReport, path: &Path) -> Result<()> {
+    fs::write(path, serde_json::to_string_pretty(&render(report))?)?;
+    Ok(())
+}
@@ -0,0 +1,782 @@
+use std::collections::HashSet;
+use std::fs::File;
+use std::io::Write;
+use std::path::PathBuf;
+use std::time::{Duration, Instant, SystemTime};
+
+use colored::*;
+use indicatif::{ProgressBar, ProgressStyle};
+use regex::Regex;
+use reqwest::blocking::ClientBuilder;
+use reqwest::redirect::Policy;
+use reqwest::header::{HeaderMap, HeaderName};
+use tempdir::TempDir;
+
+use crate::Result;
+use crate::api::filter_api_path;
+use crate::chunks::discover_chunks;
+use crate::deps::discover_dependencies;
+use crate::output::report_stem;
+use crate::sourcemap::{SourceFile, declared_map_url, guessed_map_url, parse_source_map, save_sources};
+use crate::config::Config;
+use crate::crawler::{
+    CrawlContext, DEFAULT_MAX_VARIANTS, LinkExtractor, Scope, Seeds, crawl, default_extractors,
+    discover_seeds,
+};
+use crate::http::{HttpClient, HttpError, HttpOptions};
+use crate::model::{DiscoveredUrl, Finding, Phase, ProbeResult, ScanReport, UrlKind};
+use crate::sensitive::{detect_sensitive_info, login_redirect_finding};
+use crate::urls::{canonicalize_url, extract_domain, is_blacklisted, is_noise_js_file};
+
+// 扫描参数
+#[derive(Debug, Clone)]
+pub struct ScanOptions {
+    url: String,
+    cookie: Option<String>,
+    authorization: Option<String>,
+    user_agent: Option<String>,
+    headers: Vec<(String, String)>,
+    max_depth: u8,
+    third_party_depth: u8,
+    concurrency: usize,
+    seeds: bool,
+    robots_seeds: bool,
+    max_pages: Option<usize>,
+    max_duration: Option<Duration>,
+    max_variants: usize,
+    js_depth: u8,
+    scope: Scope,
+    follow_third_party: bool,
+    source_map_dir: Option<PathBuf>,
+    http: HttpOptions,
+    config: Option<Config>,
+}
+
+impl ScanOptions {
+    pub fn new(url: impl Into<String>) -> Self {
+        let url = url.into();
+        let url = url.split('#').next().unwrap_or(&url).to_string();
+        ScanOptions {
+            url,
+            cookie: None,
+            authorization: None,
+            user_agent: None,
+            headers: Vec::new(),
+            max_depth: 3,
+            third_party_depth: 1,
+            concurrency: 8,
+            seeds: true,
+            robots_seeds: false,
+            max_pages: None,
+            max_duration: None,
+            max_variants: DEFAULT_MAX_VARIANTS,
+            js_depth: 3,
+            scope: Scope::Host,
+            follow_third_party: false,
+            source_map_dir: None,
+            http: HttpOptions::default(),
+            config: None,
+        }
+    }
+
+    pub fn cookie(mut self, cookie: impl Into<String>) -> Self {
+        self.cookie = Some(cookie.into());
+        self
+    }
+
+    pub fn authorization(mut self, authorization: impl Into<String>) -> Self {
+        self.authorization = Some(authorization.into());
+        self
+    }
+
+    pub fn user_agent(mut self, user_agent: impl Into<String>) -> Self {
+        self.user_agent = Some(user_agent.into());
+        self
+    }
+
+    // 追加自定义请求头，可多次调用
+    pub fn header(mut self, name: impl Into<String>, value: impl Into<String>) -> Self {
+        self.headers.push((name.into(), value.into()));
+        self
+    }
+
+    // 主域名页面最大爬取深度，默认 3
+    pub fn max_depth(mut self, depth: u8) -> Self {
+        self.max_depth = depth;
+        self
+    }
+
+    // 第三方页面最大爬取深度，默认 1，0 表示不爬取第三方页面
+    pub fn third_party_depth(mut self, depth: u8) -> Self {
+        self.third_party_depth = depth;
+        self
+    }
+
+    // 爬取并发线程数，默认 8
+    pub fn concurrency(mut self, concurrency: usize) -> Self {
+        self.concurrency = concurrency.max(1);
+        self
+    }
+
+    // 是否从 robots.txt 与 sitemap.xml 获取种子URL，默认开启
+    pub fn seeds(mut self, seeds: bool) -> Self {
+        self.seeds = seeds;
+        self
+    }
+
+    // robots.txt 的 Disallow 路径是否也作为种子爬取，默认关闭，只记为敏感信息
+    pub fn robots_seeds(mut self, robots_seeds: bool) -> Self {
+        self.robots_seeds = robots_seeds;
+        self
+    }
+
+    // 最多抓取的页面数
+    pub fn max_pages(mut self, max: usize) -> Self {
+        self.max_pages = Some(max);
+        self
+    }
+
+    // 单个目标的最长扫描时间，超时后跳过剩余的爬取、JS提取与探测
+    pub fn max_duration(mut self, duration: Duration) -> Self {
+        self.max_duration = Some(duration);
+        self
+    }
+
+    // 只有参数值不同或路径段重复的URL视为同一模式，每个模式最多抓取的页面数，默认 20，0 表示不限制
+    pub fn max_variants(mut self, max: usize) -> Self {
+        self.max_variants = max;
+        self
+    }
+
+    // 跟随脚本中 import、Worker 等引用的最大深度，默认 3，0 表示只处理页面直接引用的JS
+    pub fn js_depth(mut self, depth: u8) -> Self {
+        self.js_depth = depth;
+        self
+    }
+
+    // 属于目标的主机范围，默认只包含目标主机
+    pub fn scope(mut self, scope: Scope) -> Self {
+        self.scope = scope;
+        self
+    }
+
+    // 是否爬取范围外的第三方页面，默认关闭；开启后深度由 third_party_depth 控制
+    pub fn follow_third_party(mut self, follow: bool) -> Self {
+        self.follow_third_party = follow;
+        self
+    }
+
+    // source map 还原出的源码保存目录，按目标主机分子目录；未设置时只分析不保存
+    pub fn source_map_dir(mut self, dir: impl Into<PathBuf>) -> Self {
+        self.source_map_dir = Some(dir.into());
+        self
+    }
+
+    // 每个主机每秒最多请求数
+    pub fn rate_limit(mut self, requests_per_second: f64) -> Self {
+        self.http.rate_limit = Some(requests_per_second);
+        self
+    }
+
+    // 每次请求前随机等待 0~jitter
+    pub fn jitter(mut self, jitter: Duration) -> Self {
+        self.http.jitter = jitter;
+        self
+    }
+
+    // 每个主机同时进行中的最大请求数
+    pub fn max_per_host(mut self, max: usize) -> Self {
+        self.http.max_per_host = Some(max.max(1));
+        self
+    }
+
+    // 是否自动跟随跳转，默认跟随；关闭后逐跳记录跳转链，探测结果显示第一个响应的状态码
+    pub fn follow_redirects(mut self, follow: bool) -> Self {
+        self.http.no_follow_redirects = !follow;
+        self
+    }
+
+    // 整个扫描的请求总数上限
+    pub fn max_requests(mut self, max: usize) -> Self {
+        self.http.max_requests = Some(max);
+        self
+    }
+
+    // 未设置时使用 Config::load() 读取当前目录下的配置文件
+    pub fn config(mut self, config: Config) -> Self {
+        self.config = Some(config);
+        self
+    }
+
+    pub fn url(&self) -> &str {
+        &self.url
+    }
+}
+
+// 不含目标URL的默认参数，用于批量扫描与探测
+impl Default for ScanOptions {
+    fn default() -> Self {
+        ScanOptions::new("")
+    }
+}
+
+// 待处理的JS：引用深度（页面直接引用的为 0）与引用它的页面，链式引用的脚本沿用上级的页面
+#[derive(Debug, Clone)]
+struct QueuedScript {
+    url: String,
+    depth: u8,
+    page: String,
+}
+
+// 扫描器：爬取 -> JS提取 -> 存活探测
+pub struct Scanner {
+    options: ScanOptions,
+    config: Config,
+    client: HttpClient,
+    extractors: Vec<Box<dyn LinkExtractor>>,
+}
+
+impl Scanner {
+    pub fn new(options: ScanOptions) -> Result<Self> {
+        if !options.url.is_empty() {
+            check_target(&options.url)?;
+        }
+        let config = options.config.clone().unwrap_or_else(Config::load);
+
+        let mut headers = HeaderMap::new();
+        if let Some(cookie_value) = &options.cookie {
+            headers.insert("Cookie", cookie_value.parse()?);
+        }
+        if let Some(auth_value) = &options.authorization {
+            headers.insert("Authorization", auth_value.parse()?);
+        }
+        if let Some(ua_value) = &options.user_agent {
+            headers.insert("User-Agent", ua_value.parse()?);
+        }
+        for (name, value) in &options.headers {
+            headers.insert(HeaderName::from_bytes(name.as_bytes())?, value.parse()?);
+        }
+        let mut builder = ClientBuilder::new()
+            .danger_accept_invalid_certs(true)
+            .default_headers(headers);
+        if options.http.no_follow_redirects {
+            builder = builder.redirect(Policy::none());
+        }
+        let client = builder.build()?;
+        let client = HttpClient::new(client, options.http.clone());
+
+        Ok(Scanner {
+            options,
+            config,
+            client,
+            extractors: default_extractors(),
+        })
+    }
+
+    // 追加自定义链接提取器
+    pub fn link_extractor(mut self, extractor: impl LinkExtractor + 'static) -> Self {
+        self.extractors.push(Box::new(extractor));
+        self
+    }
+
+    pub fn options(&self) -> &ScanOptions {
+        &self.options
+    }
+
+    pub fn config(&self) -> &Config {
+        &self.config
+    }
+
+    // 按 max_duration 计算的截止时间，从报告开始时间算起
+    fn deadline(&self, report: &ScanReport) -> Option<Instant> {
+        self.options.max_duration.map(|max| {
+            let elapsed = report.started_at.elapsed().unwrap_or_default();
+            Instant::now() + max.saturating_sub(elapsed)
+        })
+    }
+
+    // 完整流程
+    pub fn scan(&self) -> Result<ScanReport> {
+        if self.options.url.is_empty() {
+            return Err("未指定目标URL".into());
+        }
+        self.scan_url(&self.options.url)
+    }
+
+    // 使用同一客户端与配置扫描任意目标
+    pub fn scan_url(&self, url: &str) -> Result<ScanReport> {
+        let url = url.split('#').next().unwrap_or(url);
+        check_target(url)?;
+        let mut report = ScanReport::new(url);
+        self.crawl(&mut report)?;
+        self.extract_js(&mut report)?;
+        self.probe(&mut report);
+        report.finished_at = Some(SystemTime::now());
+        Ok(report)
+    }
+
+    // 爬取页面，收集 HTML/静态资源、域名与敏感信息
+    pub fn crawl(&self, report: &mut ScanReport) -> Result<()> {
+        let spinner = new_spinner();
+        spinner.set_message(format!(
+            "{} {}",
+            "正在请求URL:".bright_blue(),
+            report.base_url.green()
+        ));
+
+        let ctx = CrawlContext {
+            client: &self.client,
+            blacklist: &self.config.blacklist,
+            max_depth: self.options.max_depth,
+            third_party_depth: if self.options.follow_third_party {
+                self.options.third_party_depth
+            } else {
+                0
+            },
+            concurrency: self.options.concurrency,
+            extractors: &self.extractors,
+            scope: &self.options.scope,
+            max_pages: self.options.max_pages,
+            deadline: self.deadline(report),
+            max_variants: self.options.max_variants,
+        };
+        let seeds = if self.options.seeds {
+            discover_seeds(
+                &self.client,
+                &report.base_url,
+                &self.options.scope,
+                self.options.robots_seeds,
+            )
+        } else {
+            Seeds::default()
+        };
+        report.findings.extend(seeds.findings);
+        let state = crawl(&ctx, &report.base_url, &seeds.urls);
+        spinner.finish_and_clear();
+
+        report.urls.extend(state.urls);
+        report.findings.extend(state.findings);
+        report.domains.extend(state.domains);
+        report.forms.extend(state.forms);
+        report.diagnostics.extend(state.diagnostics);
+        report.collapsed.extend(state.collapsed);
+        report.crawled.extend(state.crawled);
+        report.truncated |= state.truncated;
+        report.scripts.extend(state.scripts);
+        report.sort();
+        Ok(())
+    }
+
+    // 下载主域名JS，连同爬取时收集的内联脚本提取基础URL与接口地址
+    pub fn extract_js(&self, report: &mut ScanReport) -> Result<()> {
+        let spinner = new_spinner();
+        let temp_dir = TempDir::new("js_files")?;
+        println!(
+            "{} {:?}",
+            "\n临时文件夹创建于:".truecolor(255, 215, 0),
+            temp_dir.path()
+        );
+
+        let deadline = self.deadline(report);
+        let url_re = Regex::new(r#"(https?://[^\s'"]+)"#)?;
+        // 待处理的JS
+        let mut js_urls: Vec<QueuedScript> = report
+            .urls_of(UrlKind::Js)
+            .map(|u| QueuedScript {
+                url: u.url.clone(),
+                depth: 0,
+                page: u.source.clone(),
+            })
+            .collect();
+        // 已下载的非噪音JS内容，接口提取时直接使用，不再重复请求
+        let mut js_contents: Vec<(String, String)> = Vec::new();
+        // 已请求过的 source map 与从中还原的应用源码
+        let mut maps = HashSet::new();
+        let mut sources: Vec<SourceFile> = Vec::new();
+
+        // 内联的 webpack 运行时中的 chunk 与内联脚本加载的脚本
+        let scripts = std::mem::take(&mut report.scripts);
+        for script in &scripts {
+            let chunks = discover_chunks(&script.content, &script.source);
+            self.queue_scripts(chunks, &script.source, 0, &script.source, &mut js_urls, report);
+            let dependencies = discover_dependencies(&script.content, &script.source, &script.source);
+            self.queue_scripts(dependencies, &script.source, 1, &script.source, &mut js_urls, report);
+        }
+
+        // 提取JS中的URL（只限主域名），发现的 chunk 与依赖脚本追加到列表末尾继续处理
+        spinner.set_message("提取JS中的基础URL...");
+        let mut index = 0;
+        while let Some(QueuedScript { url: js_url, depth, page }) = js_urls.get(index).cloned() {
+            index += 1;
+            let url = js_url.as_str();
+            if is_expired(deadline) {
+                println!("{}", "[*]已达到最长扫描时间，停止处理JS".red());
+                break;
+            }
+            if !self.options.scope.contains(&report.base_url, url) {
+                println!("{} {}", "[*]跳过非主域名JS:".red(), url.red());
+                continue;
+            }
+            println!("{} {}", "[*]准备处理JS:".green(), url.green());
+            match self.client.get(url) {
+                Ok(js_response) if js_response.status().is_success() => {
+                    let map_header = ["sourcemap", "x-sourcemap"].iter().find_map(|name| {
+                        js_response
+                            .headers()
+                            .get(*name)
+                            .and_then(|v| v.to_str().ok())
+                            .map(str::to_string)
+                    });
+                    let js_content = match js_response.text() {
+                        Ok(text) => text,
+                        Err(e) => {
+                            println!("{} {} - {}", "[*]JS文件读取失败:".red(), url.red(), e.to_string().red());
+                            continue;
+                        }
+                    };
+                    // chunk 与加载它的运行时同属一层，不计入依赖深度
+                    let chunks = discover_chunks(&js_content, url);
+                    self.queue_scripts(chunks, url, depth, &page, &mut js_urls, report);
+                    // Worker、Service Worker 与动态 <script> 相对加载该脚本的页面解析
+                    let dependencies = discover_dependencies(&js_content, url, &page);
+                    self.queue_scripts(dependencies, url, depth.saturating_add(1), &page, &mut js_urls, report);
+                    // 没有声明 source map 时尝试 <脚本>.map
+                    let map_url = declared_map_url(url, map_header.as_deref(), &js_content)
+                        .unwrap_or_else(|| guessed_map_url(url));
+                    if maps.insert(map_url.clone()) {
+                        sources.extend(self.analyze_source_map(&url_re, url, &map_url, report));
+                    }
+                    // 噪音JS（第三方库、入口包）只用于还原 chunk 映射
+                    if is_noise_js_file(url) {
+                        println!("{} {}", "[*]跳过噪音JS文件:".red(), url.red());
+                        continue;
+                    }
+                    report
+                        .findings
+                        .extend(detect_sensitive_info(
+                        &js_content,
+                        &report.base_url,
+                        url,
+                        Phase::Js,
+                    ));
+
+                    let file_name = url
+                        .split(['?', '#'])
+                        .next()
+                        .and_then(|path| path.split('/').next_back())
+                        .filter(|name| !name.is_empty())
+                        .unwrap_or("temp.js");
+                    let file_path = temp_dir.path().join(file_name);
+                    let mut file = File::create(&file_path)?;
+                    file.write_all(js_content.as_bytes())?;
+
+                    println!("{} {}", "[*]解析JS文件:".yellow(), url.yellow());
+                    self.discover_base_urls(&url_re, &js_content, report);
+                    js_contents.push((js_url, js_content));
+                }
+                Err(HttpError::BudgetExhausted) => {
+                    report.truncated = true;
+                    println!("{}", "[*]请求总数已达上限，停止处理JS".red());
+                    break;
+                }
+                Err(e) => println!(
+                    "{} {} - {}",
+                    "[*]JS文件访问失败:".red(),
+                    url.red(),
+                    e.to_string().red()
+                ),
+                Ok(js_response) => println!(
+                    "{} {} - {}",
+                    "[*]JS文件状态异常:".red(),
+                    url.red(),
+                    js_response.status().to_string().red()
+                ),
+            }
+        }
+
+        // 页面内联脚本与事件处理器
+        for script in &scripts {
+            println!("{} {}", "[*]解析内联脚本:".yellow(), script.source.yellow());
+            self.discover_base_urls(&url_re, &script.content, report);
+        }
+
+        println!("{} {:?}", "[*]最终base_urls:".cyan(), report.base_urls);
+
+        // 提取拼接API路径
+        spinner.set_message("解析JS中的接口地址...");
+        let api_regex = Regex::new(
+            r#"(?:["']|/)(/[^"\s;}{><\p{Han}]+|api/?(?:[^"\s;}{><\p{Han}]+)?)(?:["']|/)?(?:[^"\s;}{><\p{Han}]*)"#,
+        )?;
+        for (url, js_content) in &js_contents {
+            self.extract_apis(&api_regex, js_content, url, report);
+        }
+        // 内联脚本中的接口归属于所在页面，原始源码中的归属于源文件
+        for script in &scripts {
+            self.extract_apis(&api_regex, &script.content, &script.source, report);
+        }
+        for source in &sources {
+            self.extract_apis(&api_regex, &source.content, &source.path, report);
+        }
+        report.sort();
+
+        spinner.finish_with_message("[*]JS接口提取完成");
+        Ok(())
+    }
+
+    // 下载并解析 source map：记录泄露，保存源码树，对应用源码做敏感信息检测与基础URL提取
+    // 返回应用源码（不含第三方依赖），供接口提取使用
+    fn analyze_source_map(
+        &self,
+        url_re: &Regex,
+        js_url: &str,
+        map_url: &str,
+        report: &mut ScanReport,
+    ) -> Vec<SourceFile> {
+        // 地址由目标的响应头或注释决定，范围外与黑名单中的主机不请求
+        if !self.options.scope.contains(&report.base_url, map_url)
+            || is_blacklisted(map_url, &self.config.blacklist)
+        {
+            println!("{} {}", "[*]跳过范围外的Source Map:".red(), map_url.red());
+            return Vec::new();
+        }
+        let text = match self.client.get(map_url) {
+            Ok(response) if response.status().is_success() => match response.text() {
+                Ok(text) => text,
+                Err(_) => return Vec::new(),
+            },
+            _ => return Vec::new(),
+        };
+        let Some(files) = parse_source_map(&text) else {
+            return Vec::new();
+        };
+        println!(
+            "{} {} ({} 个源文件)",
+            "[!]发现 Source Map:".purple(),
+            map_url.purple(),
+            files.len()
+        );
+        report
+            .urls
+            .push(DiscoveredUrl::new(map_url, UrlKind::Static, js_url, Phase::Js));
+        report
+            .findings
+            .push(Finding::new("Source Map泄露", map_url, js_url, Phase::Js));
+
+        if let Some(dir) = &self.options.source_map_dir
+            && let Ok(stem) = report_stem(report)
+            && let Err(e) = save_sources(&dir.join(stem), &files)
+        {
+            println!("{} {} - {}", "[*]源码保存失败:".red(), map_url.red(), e.to_string().red());
+        }
+
+        let files: Vec<SourceFile> = files.into_iter().filter(|file| !file.is_vendor()).collect();
+        for file in &files {
+            println!("{} {}", "[*]解析原始源码:".yellow(), file.path.yellow());
+            report.findings.extend(detect_sensitive_info(
+                &file.content,
+                &report.base_url,
+                &file.path,
+                Phase::Js,
+            ));
+            self.discover_base_urls(url_re, &file.content, report);
+        }
+        files
+    }
+
+    // 记录脚本引用的范围内 chunk 与依赖脚本，未超过深度的加入待处理的JS列表，超过的只记录不处理
+    fn queue_scripts(
+        &self,
+        scripts: Vec<String>,
+        source: &str,
+        depth: u8,
+        page: &str,
+        js_urls: &mut Vec<QueuedScript>,
+        report: &mut ScanReport,
+    ) {
+        for script in scripts {
+            let script = canonicalize_url(&script);
+            if js_urls.iter().any(|queued| queued.url == script)
+                || !self.options.scope.contains(&report.base_url, &script)
+            {
+                continue;
+            }
+            report
+                .urls
+                .push(DiscoveredUrl::new(script.as_str(), UrlKind::Js, source, Phase::Js));
+            if depth > self.options.js_depth {
+                println!("{} {}", "[*]超过JS依赖深度，不再处理:".red(), script.red());
+                continue;
+            }
+            js_urls.push(QueuedScript {
+                url: script,
+                depth,
+                page: page.to_string(),
+            });
+        }
+    }
+
+    // 脚本中的完整URL：记录域名，范围内的作为拼接接口用的基础URL
+    fn discover_base_urls(&self, url_re: &Regex, content: &str, report: &mut ScanReport) {
+        for cap in url_re.captures_iter(content) {
+            let extracted_url = cap[1].to_string();
+            println!("{} {}", "[*]尝试提取URL:".yellow(), extracted_url.yellow());
+            if let Some(domain) = extract_domain(&extracted_url) {
+                report.domains.push(domain);
+            }
+            if !is_blacklisted(&extracted_url, &self.config.blacklist)
+                && !report.base_urls.contains(&extracted_url)
+            {
+                if self.options.scope.contains(&report.base_url, &extracted_url) {
+                    println!("{} {}", "[*]添加基础URL:".green(), extracted_url.green());
+                    report.base_urls.push(extracted_url);
+                } else {
+                    println!("{} {}", "[*]跳过非主域名URL:".red(), extracted_url.red());
+                }
+            } else {
+                println!(
+                    "{} {}",
+                    "[*]跳过重复或黑名单URL:".red(),
+                    extracted_url.red()
+                );
+            }
+        }
+    }
+
+    // 脚本中的相对路径与基础URL拼接为接口地址，source 为脚本或页面地址
+    fn extract_apis(&self, api_regex: &Regex, content: &str, source: &str, report: &mut ScanReport) {
+        for cap in api_regex.captures_iter(content) {
+            // 正则只排除双引号，单引号与反引号字符串的结束引号及其后内容需要截掉
+            let path = cap[1].split(['\'', '`']).next().unwrap_or_default().to_string();
+            if path.is_empty() {
+                continue;
+            }
+            println!("{} {}", "[*]提取相对路径:".blue(), path.blue());
+            let apis = filter_api_path(
+                &path,
+                &report.base_urls,
+                &self.config.api_core,
+                &self.config.noise_strings,
+            );
+            println!("{} {:?}", "[*]拼接结果:".green(), apis);
+            report.urls.extend(
+                apis.into_iter()
+                    .map(|api| DiscoveredUrl::new(canonicalize_url(&api), UrlKind::Api, source, Phase::Js)),
+            );
+        }
+    }
+
+    // 探测所有URL的存活状态
+    pub fn probe(&self, report: &mut ScanReport) {
+        let (probes, exhausted) = self.probe_urls_until(&report.all_urls(), self.deadline(report));
+        report.probes = probes;
+        report.truncated |= exhausted;
+        // 爬取阶段已记录的登录跳转不再重复
+        let findings: Vec<Finding> = report
+            .probes
+            .iter()
+            .filter_map(|probe| login_redirect_finding(&probe.url, probe.location.as_deref()?, Phase::Probe))
+            .filter(|finding| {
+                !report
+                    .findings
+                    .iter()
+                    .any(|f| f.kind == finding.kind && f.value == finding.value)
+            })
+            .collect();
+        report.findings.extend(findings);
+    }
+
+    // 逐个请求URL，返回探测结果
+    pub fn probe_urls(&self, all_urls: &[String]) -> Vec<ProbeResult> {
+        self.probe_urls_until(all_urls, None).0
+    }
+
+    // 超过 deadline 后不再探测剩余URL；同时返回是否因请求总数已达上限而中止
+    fn probe_urls_until(&self, all_urls: &[String], deadline: Option<Instant>) -> (Vec<ProbeResult>, bool) {
+        let mut results = Vec::new();
+        let mut exhausted = false;
+        let pb = ProgressBar::new(all_urls.len() as u64);
+        pb.set_style(
+            ProgressStyle::default_bar()
+                .template("{msg} [{elapsed_precise:.truecolor(255,165,0)}] [{bar:40.yellow/bright_black}] {pos}/{len} ({eta:.truecolor(255,165,0)})")
+                .unwrap()
+                .progress_chars("█ ")
+        );
+        pb.set_message("[*]访问URL中...");
+
+        for url in all_urls {
+            if is_expired(deadline) {
+                println!("{}", "[*]已达到最长扫描时间，停止探测".red());
+                break;
+            }
+            if is_blacklisted(url, &self.config.blacklist) {
+                println!("{} {}", "[*]黑名单URL跳过:".red(), url.red());
+                continue;
+            }
+            match self.client.get_chain(url) {
+                Ok((api_response, redirects)) => {
+                    // 不跟随跳转时状态码与长度都以第一个响应为准，自动跟随时记录最终地址
+                    let (status, content_length) = match redirects.first() {
+                        Some(hop) => (hop.status, hop.content_length),
+                        None => (api_response.status().as_u16(), api_response.content_length()),
+                    };
+                    let location = match redirects.last() {
+                        Some(hop) => Some(hop.location.clone()),
+                        // 只有规范化后仍不同才算跳转，补全末尾斜杠等不记录
+                        None => Some(api_response.url().to_string())
+                            .filter(|final_url| canonicalize_url(final_url) != canonicalize_url(url)),
+                    };
+                    results.push(ProbeResult {
+                        url: url.clone(),
+                        status: Some(status),
+                        content_length,
+                        error: None,
+                        location,
+                        redirects,
+                        timestamp: SystemTime::now(),
+                    });
+                }
+                Err(HttpError::BudgetExhausted) => {
+                    exhausted = true;
+                    println!("{}", "[*]请求总数已达上限，停止探测".red());
+                    break;
+                }
+                Err(e) => results.push(ProbeResult {
+                    url: url.clone(),
+                    status: None,
+                    content_length: None,
+                    error: Some(e.to_string()),
+                    location: None,
+                    redirects: Vec::new(),
+                    timestamp: SystemTime::now(),
+                }),
+            }
+            pb.inc(1);
+        }
+
+        pb.finish_with_message("探测URL存活完成");
+        (results, exhausted)
+    }
+}
+
+fn check_target(url: &str) -> Result<()> {
+    if !url.starts_with("http") {
+        return Err(format!("请提供完整的URL，例如 https://example.com: {}", url).into());
+    }
+    Ok(())
+}
+
+fn new_spinner() -> ProgressBar {
+    let spinner = ProgressBar::new_spinner();
+    spinner.set_style(
+        ProgressStyle::default_spinner()
+            .tick_strings(&["⠋", "⠙", "⠹", "⠸", "⠼", "⠴", "⠦", "⠧", "⠇", "⠏"])
+            .template("{spinner:.yellow} {msg}")
+            .unwrap(),
+    );
+    spinner.enable_steady_tick(Duration::from_millis(100));
+    spinner
+}
+
+fn is_expired(deadline: Option<Instant>) -> bool {
+    deadline.is_some_and(|deadline| Instant::now() >= deadline)
+}
@@ -0,0 +1,144 @@
+use regex::{Captures, Regex};
+
+use crate::model::{Finding, Phase};
+use crate::urls::{Link, STATIC_EXTENSIONS, is_login_url, normalize_url_for_crawl};
+
+// 敏感信息检测，source 为内容所在的页面或JS地址
+pub fn detect_sensitive_info(
+    content: &str,
+    base_url: &str,
+    source: &str,
+    phase: Phase,
+) -> Vec<Finding> {
+    let mut findings = Vec::new();
+
+    // AK/SK
+    let ak_sk_re =
+        Regex::new(r#"["']?([a-zA-Z0-9_]+)\s*[=:]\s*["']([A-Za-z0-9\-]{16,64})["']"#).unwrap();
+    for cap in ak_sk_re.captures_iter(content) {
+        let var_name = cap[1].to_string();
+        let value = cap[2].to_string();
+        if (var_name.to_uppercase().contains("ACCESSKEY") ||
+            var_name.to_uppercase().contains("SECRETKEY") ||
+            var_name.to_uppercase().contains("AK") ||
+            var_name.to_uppercase().contains("SK")) &&
+            value.chars().any(|c| c.is_ascii_digit()) &&
+            value.chars().any(|c| c.is_ascii())
+        {
+            findings.push(Finding::new("AK/SK", format!("{} = {}", var_name, value), source, phase).at_line(line_of(content, &cap)));
+        }
+    }
+
+    // 邮箱
+    let email_re = Regex::new(r#""([a-zA-Z0-9._%+-]+@[a-zA-Z0-9.-]+\.[a-zA-Z]{2,})""#).unwrap();
+    for cap in email_re.captures_iter(content) {
+        let email = cap[1].to_string();
+        if !STATIC_EXTENSIONS.iter().any(|ext| email.ends_with(ext)) {
+            findings.push(email_finding(&email, source, phase).at_line(line_of(content, &cap)));
+        }
+    }
+
+    // 手机号
+    let phone_re = Regex::new(r#""(1[3-9]\d{9})""#).unwrap();
+    for cap in phone_re.captures_iter(content) {
+        let phone = cap[1].to_string();
+        findings.push(phone_finding(&phone, source, phase).at_line(line_of(content, &cap)));
+    }
+
+    // Token
+    let token_re = Regex::new(r#""(token|auth_token|bearer)\s*([A-Za-z0-9\-_]{16,128})""#).unwrap();
+    for cap in token_re.captures_iter(content) {
+        findings.push(Finding::new("Token", cap[0].to_string(), source, phase).at_line(line_of(content, &cap)));
+    }
+
+    // APIKey
+    let apikey_re = Regex::new(r#""apikey\s*([A-Za-z0-9\-_]{16,64})""#).unwrap();
+    for cap in apikey_re.captures_iter(content) {
+        findings.push(Finding::new("APIKey", cap[1].to_string(), source, phase).at_line(line_of(content, &cap)));
+    }
+
+    // JDBC连接
+    let jdbc_re = Regex::new(r#""(jdbc:[a-z]+://[a-zA-Z0-9.-]+:[0-9]+/[a-zA-Z0-9_]+)""#).unwrap();
+    for cap in jdbc_re.captures_iter(content) {
+        findings.push(Finding::new("JDBC连接", cap[1].to_string(), source, phase).at_line(line_of(content, &cap)));
+    }
+
+    // 密码
+    let password_re = Regex::new(r#""password\s*=\s*([A-Za-z0-9!@#$%^&*]{8,32})""#).unwrap();
+    for cap in password_re.captures_iter(content) {
+        findings.push(Finding::new("密码", cap[1].to_string(), source, phase).at_line(line_of(content, &cap)));
+    }
+
+    // WebSocket接口
+    let ws_re = Regex::new(r#""((ws|wss)://[a-zA-Z0-9.-]+(:[0-9]{1,5})?(/[^\"\n]*)?)""#).unwrap();
+    for cap in ws_re.captures_iter(content) {
+        findings.push(Finding::new("WebSocket接口", cap[1].to_string(), source, phase).at_line(line_of(content, &cap)));
+    }
+
+    // 备份文件和配置文件
+    let backup_re =
+        Regex::new(r#"["']([^"\s;}{><\p{Han}]+\.(?:zip|tar\.gz|bak|config))["']"#).unwrap();
+    for cap in backup_re.captures_iter(content) {
+        let path = cap[1].to_string();
+        let full_url = match normalize_url_for_crawl(&path, base_url) {
+            Ok(Link::Http(full_url)) => full_url,
+            _ => path.clone(),
+        };
+        let file_type = if path.ends_with(".config") {
+            "配置文件"
+        } else {
+            "备份文件"
+        };
+        findings.push(Finding::new(file_type, full_url, source, phase).at_line(line_of(content, &cap)));
+    }
+
+    findings
+}
+
+// 跳转到登录页的地址，通常是需要鉴权的接口
+pub fn login_redirect_finding(url: &str, location: &str, phase: Phase) -> Option<Finding> {
+    is_login_url(location)
+        .then(|| Finding::new("登录跳转", format!("{} -> {}", url, location), url, phase))
+}
+
+// 邮箱，附带按顶级域名推测的国家
+pub fn email_finding(email: &str, source: &str, phase: Phase) -> Finding {
+    let country = match email.split('.').next_back().unwrap_or("") {
+        "cn" => "中国",
+        "jp" => "日本",
+        "uk" => "英国",
+        _ => "未知",
+    };
+    Finding::new("邮箱", format!("{} ({})", email, country), source, phase)
+}
+
+// 手机号，附带按号段推测的运营商；非大陆手机号记为未知运营商
+pub fn phone_finding(phone: &str, source: &str, phase: Phase) -> Finding {
+    let is_mobile =
+        phone.len() == 11 && phone.starts_with('1') && phone.bytes().all(|b| b.is_ascii_digit());
+    let operator = if !is_mobile {
+        "未知运营商"
+    } else {
+        match &phone[0..3] {
+            "134" | "135" | "136" | "137" | "138" | "139" | "147" | "148" | "150" | "151"
+            | "152" | "157" | "158" | "159" | "165" | "172" | "178" | "182" | "183" | "184"
+            | "187" | "188" | "195" | "197" | "198" => "中国移动",
+            "133" | "149" | "153" | "173" | "174" | "177" | "180" | "181" | "189" | "190"
+            | "191" => "中国电信",
+            "130" | "131" | "132" | "145" | "146" | "155" | "156" | "166" | "171" | "175"
+            | "176" | "185" | "186" | "196" | "199" => "中国联通",
+            "162" => "中国电信虚拟运营商",
+            "167" => "中国联通虚拟运营商",
+            "192" => "中国广电",
+            "170" => "虚拟运营商",
+            _ => "未知运营商",
+        }
+    };
+    Finding::new("手机号", format!("{} ({})", phone, operator), source, phase)
+}
+
+// 匹配位置所在的行号，从 1 开始
+fn line_of(content: &str, cap: &Captures) -> usize {
+    let start = cap.get(0).map_or(0, |m| m.start());
+    content[..start].matches('\n').count() + 1
+}
@@ -0,0 +1,183 @@
+// Source Map：定位脚本对应的 .map，还原 sourcesContent 中的原始源码
+
+use std::fs;
+use std::io;
+use std::path::{Component, Path, PathBuf};
+
+use serde_json::Value;
+
+// 从 source map 还原的原始文件
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SourceFile {
+    // 清理后的相对路径，例如 src/api/user.js
+    pub path: String,
+    pub content: String,
+}
+
+impl SourceFile {
+    // 第三方依赖与打包工具生成的代码
+    pub fn is_vendor(&self) -> bool {
+        self.path.contains("node_modules/") || self.path.starts_with("webpack/")
+    }
+}
+
+// 脚本声明的 source map 地址：优先 SourceMap / X-SourceMap 响应头，其次末尾的 sourceMappingURL 注释
+// 内联的 data: 地址不处理
+pub fn declared_map_url(js_url: &str, header: Option<&str>, content: &str) -> Option<String> {
+    let declared = header.map(str::to_string).or_else(|| {
+        content
+            .lines()
+            .rev()
+            .take(5)
+            .find_map(|line| {
+                let line = line.trim();
+                line.strip_prefix("//# sourceMappingURL=")
+                    .or_else(|| line.strip_prefix("//@ sourceMappingURL="))
+            })
+            .map(|value| value.trim_end_matches("*/").trim().to_string())
+    })?;
+    if declared.is_empty() || declared.starts_with("data:") {
+        return None;
+    }
+    url::Url::parse(js_url)
+        .and_then(|base| base.join(&declared))
+        .ok()
+        .map(String::from)
+}
+
+// 没有声明时按惯例尝试的地址：脚本地址（不含查询参数）加 .map
+pub fn guessed_map_url(js_url: &str) -> String {
+    let path = js_url.split(['?', '#']).next().unwrap_or(js_url);
+    format!("{}.map", path)
+}
+
+// 解析 source map，返回带源码的文件；不是 source map 时返回 None
+pub fn parse_source_map(text: &str) -> Option<Vec<SourceFile>> {
+    let map: Value = serde_json::from_str(text).ok()?;
+    let sources = map.get("sources")?.as_array()?;
+    map.get("mappings")?;
+    let contents = map.get("sourcesContent").and_then(Value::as_array);
+    let source_root = map.get("sourceRoot").and_then(Value::as_str).unwrap_or("");
+
+    let files = sources
+        .iter()
+        .enumerate()
+        .filter_map(|(i, source)| {
+            let content = contents?.get(i)?.as_str()?;
+            let path = source_path(&format!("{}{}", source_root, source.as_str()?));
+            Some(SourceFile {
+                path,
+                content: content.to_string(),
+            })
+        })
+        .collect();
+    Some(files)
+}
+
+// 源码路径转为安全的相对路径：去掉 webpack:// 等协议、查询参数、. / .. 段，
+// 以及 C:、\\?\ 等含 Windows 盘符或非法字符的段
+pub fn source_path(source: &str) -> String {
+    let source = source.split('?').next().unwrap_or(source);
+    let source = match source.split_once("://") {
+        Some((_, rest)) => rest,
+        None => source,
+    };
+    let segments: Vec<&str> = source
+        .split(['/', '\\'])
+        .map(|segment| segment.trim_start_matches('~'))
+        .filter(|segment| {
+            !segment.is_empty()
+                && *segment != "."
+                && *segment != ".."
+                && !segment.contains([':', '*', '?', '"', '<', '>', '|'])
+        })
+        .collect();
+    if segments.is_empty() {
+        "unknown".to_string()
+    } else {
+        segments.join("/")
+    }
+}
+
+// 按原始目录结构写出源码，路径只由普通段组成，且必须位于 dir 之内
+pub fn save_sources(dir: &Path, files: &[SourceFile]) -> io::Result<()> {
+    for file in files {
+        let relative: PathBuf = Path::new(&file.path)
+            .components()
+            .filter_map(|component| match component {
+                Component::Normal(part) => Some(part),
+                _ => None,
+            })
+            .collect();
+        let path = dir.join(relative);
+        if path == dir || !path.starts_with(dir) {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                format!("源码路径不安全: {}", file.path),
+            ));
+        }
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        fs::write(path, &file.content)?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn source_path_drops_traversal_and_drive_prefixes() {
+        assert_eq!(source_path("webpack:///../../x"), "x");
+        assert_eq!(
+            source_path("webpack:///./src/api/user.js?5a1b"),
+            "src/api/user.js"
+        );
+        assert_eq!(source_path("C:\\.."), "unknown");
+        assert_eq!(source_path("C:\\Users\\dev\\app.js"), "Users/dev/app.js");
+        assert_eq!(source_path("\\\\?\\C:\\z.js"), "unknown");
+        assert_eq!(source_path(""), "unknown");
+        assert_eq!(source_path("a/../../b"), "a/b");
+        assert_eq!(source_path("~/lodash/index.js"), "lodash/index.js");
+    }
+
+    #[test]
+    fn save_sources_stays_under_dir() {
+        let root = tempdir::TempDir::new("sourcemap").unwrap();
+        let dir = root.path().join("out");
+        let files: Vec<SourceFile> = [
+            "webpack:///../../x.js",
+            "C:\\..\\..\\y.js",
+            "\\\\?\\C:\\z.js",
+            "a/../../b.js",
+        ]
+        .iter()
+        .map(|source| SourceFile {
+            path: source_path(source),
+            content: "1".to_string(),
+        })
+        .chain(
+            ["../escape.js", "/abs/root.js"]
+                .iter()
+                .map(|path| SourceFile {
+                    path: path.to_string(),
+                    content: "2".to_string(),
+                }),
+        )
+        .collect();
+        save_sources(&dir, &files).unwrap();
+
+        assert!(dir.join("x.js").is_file());
+        assert!(dir.join("y.js").is_file());
+        assert!(dir.join("a/b.js").is_file());
+        assert!(dir.join("escape.js").is_file());
+        assert!(dir.join("abs/root.js").is_file());
+        let outside: Vec<_> = fs::read_dir(root.path())
+            .unwrap()
+            .map(|entry| entry.unwrap().file_name())
+            .collect();
+        assert_eq!(outside, vec![std::ffi::OsString::from("out")]);
+    }
+}
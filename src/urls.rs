@@ -0,0 +1,441 @@
+use std::fmt;
+use std::sync::OnceLock;
+
+use colored::*;
+use publicsuffix::{List, Psl};
+use url::Url;
+
+use crate::model::UrlKind;
+
+pub const HTML_EXTENSIONS: &[&str] = &[
+    ".htm", ".html", ".jhtml", ".xhtml", ".shtml", ".php", ".asp", ".jsp", ".do", ".action",
+    ".aspx", ".cfm", ".pl", ".cgi",
+];
+
+pub const STATIC_EXTENSIONS: &[&str] = &[
+    ".js", ".json", ".map", ".mjs", ".cjs", ".jsx", ".vue", ".png", ".jpg", ".jpeg", ".gif",
+    ".bmp", ".svg", ".webp", ".ico", ".tif", ".tiff", ".heic", ".apng", ".avif", ".psd", ".raw",
+    ".css", ".woff", ".woff2", ".ttf", ".eot", ".otf", ".zip", ".tar.gz", ".bak", ".config",
+    ".swf",
+];
+
+pub const JS_EXTENSIONS: &[&str] = &[".js", ".mjs", ".cjs", ".jsx", ".vue"];
+
+pub const IMAGE_EXTENSIONS: &[&str] = &[
+    ".png", ".jpg", ".jpeg", ".gif", ".bmp", ".svg", ".webp", ".ico", ".tif", ".tiff", ".heic",
+    ".apng", ".avif", ".psd", ".raw",
+];
+
+pub const FONT_EXTENSIONS: &[&str] = &[".woff", ".woff2", ".ttf", ".eot", ".otf"];
+
+pub const DOCUMENT_EXTENSIONS: &[&str] = &[
+    ".pdf", ".doc", ".docx", ".xls", ".xlsx", ".ppt", ".pptx", ".csv", ".txt", ".rtf",
+];
+
+// 不会是页面的下载类文件，爬取时跳过
+pub const DOWNLOAD_EXTENSIONS: &[&str] = &[
+    ".pdf", ".doc", ".docx", ".xls", ".xlsx", ".ppt", ".pptx", ".rar", ".7z", ".gz", ".tar",
+    ".exe", ".msi", ".apk", ".dmg", ".iso", ".mp3", ".mp4", ".avi", ".mov", ".flv", ".wav",
+];
+
+pub const NOISE_JS_FILES: &[&str] = &["vendor", "chunk-vendors", "main", "polyfills"];
+
+// 登录页地址中常见的路径段或参数名，按整词匹配
+pub const LOGIN_KEYWORDS: &[&str] = &[
+    "login", "signin", "sign-in", "sign_in", "logon", "sso", "cas", "passport", "oauth", "oauth2",
+    "authorize",
+];
+
+// 提取域名
+pub fn extract_domain(url: &str) -> Option<String> {
+    let url = url.trim();
+    if url.starts_with("http://") || url.starts_with("https://") {
+        let without_scheme = url.replacen("http://", "", 1).replacen("https://", "", 1);
+        let parts: Vec<&str> = without_scheme.split('/').collect();
+        if !parts.is_empty() && !parts[0].contains('@') {
+            return Some(parts[0].to_string());
+        }
+    }
+    None
+}
+
+// 公共后缀列表（https://publicsuffix.org），含 github.io 等私有后缀
+static PUBLIC_SUFFIXES: OnceLock<List> = OnceLock::new();
+
+fn public_suffixes() -> &'static List {
+    PUBLIC_SUFFIXES.get_or_init(|| {
+        include_str!("public_suffix_list.dat")
+            .parse()
+            .expect("内置的公共后缀列表格式错误")
+    })
+}
+
+// 可注册域名，例如 a.b.example.com.cn -> example.com.cn，a.github.io -> a.github.io；
+// IP 与无法识别的主机原样返回
+pub fn registrable_domain(host: &str) -> String {
+    let host = host.trim_end_matches('.').to_lowercase();
+    if host.parse::<std::net::IpAddr>().is_ok() || host.starts_with('[') {
+        return host;
+    }
+    public_suffixes()
+        .domain(host.as_bytes())
+        .and_then(|domain| std::str::from_utf8(domain.as_bytes()).ok().map(str::to_string))
+        .unwrap_or(host)
+}
+
+// 链接按协议分类的结果
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Link {
+    // 可爬取的 http/https 地址
+    Http(String),
+    // mailto: 中的邮箱
+    Email(String),
+    // tel: 中的号码
+    Phone(String),
+    // javascript:、data: 等无需处理的协议
+    Skipped(String),
+}
+
+// 无法解析的链接值
+#[derive(Debug, Clone)]
+pub struct UrlError {
+    pub value: String,
+    pub reason: String,
+}
+
+impl fmt::Display for UrlError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "无法解析URL {}: {}", self.value, self.reason)
+    }
+}
+
+impl std::error::Error for UrlError {}
+
+impl UrlError {
+    fn new(value: &str, reason: impl ToString) -> Self {
+        UrlError {
+            value: value.to_string(),
+            reason: reason.to_string(),
+        }
+    }
+}
+
+// 链接值的协议，小写；相对路径返回 None
+fn scheme_of(value: &str) -> Option<String> {
+    let (scheme, _) = value.split_once(':')?;
+    let mut chars = scheme.chars();
+    let valid = chars.next().is_some_and(|c| c.is_ascii_alphabetic())
+        && chars.all(|c| c.is_ascii_alphanumeric() || matches!(c, '+' | '-' | '.'));
+    valid.then(|| scheme.to_lowercase())
+}
+
+// 爬取用的URL规范化：相对路径按 base_url 拼接，其他协议按类型返回
+pub fn normalize_url_for_crawl(path: &str, base_url: &str) -> Result<Link, UrlError> {
+    let path = path.trim();
+    match scheme_of(path).as_deref() {
+        Some("http" | "https") => Url::parse(path)
+            .map(|url| Link::Http(url.to_string()))
+            .map_err(|e| UrlError::new(path, e)),
+        Some("mailto") => {
+            let address = path[7..].split('?').next().unwrap_or("");
+            let address = urlencoding::decode(address).map_or(address.to_string(), |a| a.into_owned());
+            if address.contains('@') {
+                Ok(Link::Email(address.trim().to_string()))
+            } else {
+                Err(UrlError::new(path, "邮箱地址无效"))
+            }
+        }
+        Some("tel") => {
+            let number: String = path[4..]
+                .chars()
+                .filter(|c| c.is_ascii_digit() || *c == '+')
+                .collect();
+            let number = number.strip_prefix("+86").unwrap_or(&number).to_string();
+            if number.chars().filter(|c| c.is_ascii_digit()).count() >= 5 {
+                Ok(Link::Phone(number))
+            } else {
+                Err(UrlError::new(path, "电话号码无效"))
+            }
+        }
+        Some(scheme) => Ok(Link::Skipped(scheme.to_string())),
+        None if path.is_empty() => Ok(Link::Skipped(String::new())),
+        None => {
+            let base = Url::parse(base_url).map_err(|e| UrlError::new(base_url, e))?;
+            let full_url = base.join(path).map_err(|e| UrlError::new(path, e))?;
+            println!(
+                "{} {}",
+                "[*]拼接URL(爬取):".yellow(),
+                full_url.as_str().yellow()
+            );
+            Ok(Link::Http(full_url.to_string()))
+        }
+    }
+}
+
+// URL规范化，用于去重：主机小写、去掉默认端口与锚点、查询参数排序、统一百分号编码
+pub fn canonicalize_url(url: &str) -> String {
+    let Ok(mut parsed) = Url::parse(url.trim()) else {
+        return url.trim().to_string();
+    };
+    // 解析时已将主机转为小写并去掉默认端口
+    parsed.set_fragment(None);
+    let path = normalize_percent_encoding(parsed.path());
+    parsed.set_path(&path);
+    let mut pairs: Vec<String> = parsed
+        .query()
+        .unwrap_or("")
+        .split('&')
+        .filter(|pair| !pair.is_empty())
+        .map(normalize_percent_encoding)
+        .collect();
+    if pairs.is_empty() {
+        parsed.set_query(None);
+    } else {
+        pairs.sort();
+        parsed.set_query(Some(&pairs.join("&")));
+    }
+    parsed.to_string()
+}
+
+// 非保留字符（字母、数字、-._~）解码，其余编码统一为大写十六进制
+fn normalize_percent_encoding(value: &str) -> String {
+    let bytes = value.as_bytes();
+    let mut normalized = String::with_capacity(value.len());
+    let mut i = 0;
+    while i < bytes.len() {
+        if bytes[i] == b'%'
+            && let Some(hex) = value.get(i + 1..i + 3)
+            && let Ok(byte) = u8::from_str_radix(hex, 16)
+        {
+            if byte.is_ascii_alphanumeric() || matches!(byte, b'-' | b'.' | b'_' | b'~') {
+                normalized.push(byte as char);
+            } else {
+                normalized.push_str(&format!("%{:02X}", byte));
+            }
+            i += 3;
+            continue;
+        }
+        // 多字节字符原样保留
+        let ch = value[i..].chars().next().unwrap_or_default();
+        normalized.push(ch);
+        i += ch.len_utf8().max(1);
+    }
+    normalized
+}
+
+// API拼接用的URL处理
+pub fn normalize_url_for_api(path: &str, base_url: &str, api_core: &str) -> String {
+    // 排除 Base64 编码字符串
+    if path.contains("data:")
+        || path.contains("base64")
+        || path
+            .chars()
+            .filter(|&c| c == '+' || c == '/' || c == '=')
+            .count()
+            > 5
+        || path.len() > 100
+    {
+        println!("{} {}", "[*]跳过Base64路径:".red(), path.red());
+        return String::new();
+    }
+
+    // 验证路径是否合法
+    let cleaned = urlencoding::decode(path)
+        .unwrap_or(path.to_string().into())
+        .into_owned();
+
+    let (path_part, query_part) = cleaned.split_once('?').unwrap_or((&cleaned, ""));
+    let has_query = !query_part.is_empty();
+
+    // 排除明显非 API 路径
+    if path_part.contains("image")
+        || cleaned.contains("img")
+        || cleaned.contains("css")
+        || cleaned.contains("font")
+        || cleaned.contains("svg")
+        || STATIC_EXTENSIONS.iter().any(|ext| cleaned.ends_with(ext))
+        || cleaned.contains("swf")
+        || (has_query && STATIC_EXTENSIONS.iter().any(|ext| path_part.contains(ext)))
+        || cleaned
+            .chars()
+            .filter(|&c| c == '/' || c == '+' || c == '=')
+            .count()
+            > 10
+    {
+        println!("{} {}", "[*]非API路径:".red(), cleaned.red());
+        return String::new();
+    }
+
+    let full_url = if path.starts_with("http") || path.starts_with("ws") {
+        cleaned
+    } else {
+        let base = if base_url.ends_with('/') {
+            base_url.to_string()
+        } else {
+            format!("{}/", base_url)
+        };
+        let cleaned_path = cleaned.trim_start_matches('/');
+        if cleaned_path == "api"
+            || cleaned_path == "api/"
+            || cleaned_path == "/api"
+            || cleaned_path == "/api/"
+        {
+            format!("{}{}", base, api_core.trim_matches('/'))
+        } else {
+            format!("{}{}", base, cleaned_path)
+        }
+    };
+
+    // 验证 URL 是否合法
+    if Url::parse(&full_url).is_err() {
+        println!("{} {}", "[*]无效URL:".red(), full_url.red());
+        return String::new();
+    }
+
+    full_url
+}
+
+// 按路径扩展名分类，忽略查询参数；无法判断时返回 None
+pub fn classify_url(url: &str) -> Option<UrlKind> {
+    let path = url_path(url);
+    let has_ext = |exts: &[&str]| exts.iter().any(|ext| path.ends_with(ext));
+    if has_ext(HTML_EXTENSIONS) {
+        Some(UrlKind::Html)
+    } else if has_ext(JS_EXTENSIONS) {
+        Some(UrlKind::Js)
+    } else if path.ends_with(".json") {
+        Some(UrlKind::Api)
+    } else if has_ext(IMAGE_EXTENSIONS) {
+        Some(UrlKind::Image)
+    } else if has_ext(FONT_EXTENSIONS) {
+        Some(UrlKind::Font)
+    } else if has_ext(DOCUMENT_EXTENSIONS) {
+        Some(UrlKind::Document)
+    } else if has_ext(STATIC_EXTENSIONS) || has_ext(DOWNLOAD_EXTENSIONS) {
+        Some(UrlKind::Static)
+    } else {
+        None
+    }
+}
+
+// 按响应的 Content-Type 分类
+pub fn classify_content_type(content_type: &str) -> UrlKind {
+    let mime = content_type
+        .split(';')
+        .next()
+        .unwrap_or("")
+        .trim()
+        .to_lowercase();
+    match mime.as_str() {
+        "text/html" | "application/xhtml+xml" => UrlKind::Html,
+        "application/javascript" | "application/x-javascript" | "text/javascript"
+        | "application/ecmascript" | "text/ecmascript" => UrlKind::Js,
+        "application/json" | "text/json" => UrlKind::Api,
+        "application/pdf" | "application/msword" | "application/rtf" | "text/plain" | "text/csv" => {
+            UrlKind::Document
+        }
+        "text/css" | "application/zip" | "application/gzip" | "application/x-gzip"
+        | "application/x-tar" | "application/x-shockwave-flash" => UrlKind::Static,
+        m if m.ends_with("+json") => UrlKind::Api,
+        m if m.starts_with("image/") => UrlKind::Image,
+        m if m.starts_with("font/") || m.contains("font-") || m.contains("-font") => UrlKind::Font,
+        m if m.starts_with("application/vnd.ms-")
+            || m.starts_with("application/vnd.openxmlformats-officedocument")
+            || m.starts_with("application/vnd.oasis.opendocument") =>
+        {
+            UrlKind::Document
+        }
+        m if m.starts_with("audio/") || m.starts_with("video/") => UrlKind::Static,
+        _ => UrlKind::Other,
+    }
+}
+
+// 小写的路径部分，解析失败时去掉查询参数与锚点
+fn url_path(url: &str) -> String {
+    match Url::parse(url) {
+        Ok(parsed) => parsed.path().to_lowercase(),
+        Err(_) => url.split(['?', '#']).next().unwrap_or(url).to_lowercase(),
+    }
+}
+
+// 是否为静态资源或下载文件：只看路径部分，忽略查询参数
+pub fn is_static_asset(url: &str) -> bool {
+    let path = url_path(url);
+    STATIC_EXTENSIONS
+        .iter()
+        .chain(DOWNLOAD_EXTENSIONS)
+        .any(|ext| path.ends_with(ext))
+}
+
+// 黑名单检查
+pub fn is_blacklisted(url: &str, blacklist: &[String]) -> bool {
+    if let Some(domain) = extract_domain(url) {
+        blacklist.iter().any(|black| domain.contains(black))
+    } else {
+        false
+    }
+}
+
+// 是否为登录页地址：路径段或参数名（去掉扩展名，或按 - _ 拆开后的单词）等于关键字，
+// 例如 /sso/redirect、/user/login.html、/user-login、?oauth=1；/lessons、/crossover 不算
+pub fn is_login_url(url: &str) -> bool {
+    let Ok(parsed) = Url::parse(url) else {
+        return false;
+    };
+    let segments = parsed.path_segments().into_iter().flatten().map(str::to_string);
+    let keys = parsed.query_pairs().map(|(key, _)| key.into_owned());
+    segments.chain(keys).any(|token| {
+        let token = token.to_lowercase();
+        let stem = token.split('.').next().unwrap_or_default();
+        LOGIN_KEYWORDS.contains(&stem)
+            || stem
+                .split(['-', '_'])
+                .any(|word| LOGIN_KEYWORDS.contains(&word))
+    })
+}
+
+// 噪音JS检查
+pub fn is_noise_js_file(url: &str) -> bool {
+    let file_name = url.split('/').next_back().unwrap_or("");
+    NOISE_JS_FILES
+        .iter()
+        .any(|&noise| file_name.starts_with(noise))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn registrable_domain_uses_public_suffixes() {
+        assert_eq!(registrable_domain("a.b.example.com.cn"), "example.com.cn");
+        assert_eq!(registrable_domain("www.example.co.za"), "example.co.za");
+        assert_eq!(registrable_domain("shop.example.com.mx"), "example.com.mx");
+        assert_eq!(registrable_domain("a.github.io"), "a.github.io");
+        assert_eq!(registrable_domain("API.Example.com."), "example.com");
+        assert_eq!(registrable_domain("127.0.0.1"), "127.0.0.1");
+        assert_eq!(registrable_domain("localhost"), "localhost");
+    }
+
+    #[test]
+    fn login_url_matches_whole_segments() {
+        assert!(is_login_url("http://x/login"));
+        assert!(is_login_url("http://x/user/Login.html?next=/"));
+        assert!(is_login_url("http://x/sso/redirect"));
+        assert!(is_login_url("http://x/cas/login"));
+        assert!(is_login_url("http://x/oauth2/authorize?client_id=1"));
+        assert!(is_login_url("http://x/user-login"));
+        assert!(is_login_url("http://x/auth/sign-in"));
+        assert!(is_login_url("http://x/?sso=1"));
+    }
+
+    #[test]
+    fn login_url_ignores_substrings() {
+        assert!(!is_login_url("http://x/lessons/1"));
+        assert!(!is_login_url("http://x/crossover"));
+        assert!(!is_login_url("http://x/casual/shoes"));
+        assert!(!is_login_url("http://x/oauthors"));
+        assert!(!is_login_url("http://x/blog?q=login"));
+        assert!(!is_login_url("http://login.example.com/home"));
+    }
+}